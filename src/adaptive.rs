@@ -0,0 +1,150 @@
+use core::ops::Sub;
+
+use crate::{InitializedValue, Monotonic, PhysicalDuration, State, TimedDebouncer, Value};
+
+/// Debouncer that adapts its debounce time to the observed bounce duration.
+///
+/// On every transition the bounce duration, i.e. the time from the first to the last change of
+/// the value before it settled, is multiplied by `factor`. The debounce time is then moved
+/// halfway towards this target and clamped to the configured bounds. This lets the debouncer
+/// follow inputs whose bouncing gets worse as the hardware ages.
+pub struct AdaptiveDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    min_time: M::Duration,
+    max_time: M::Duration,
+    factor: f32,
+}
+
+impl<M, T> AdaptiveDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value. The debounce time starts at `min_time`.
+    pub fn new(
+        initial_value: T,
+        min_time: M::Duration,
+        max_time: M::Duration,
+        factor: f32,
+    ) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, min_time),
+            min_time,
+            max_time,
+            factor,
+        }
+    }
+}
+impl<M: Monotonic, T, V: Value<T = T>> AdaptiveDebouncer<M, T, V>
+where
+    M::Duration: Copy,
+{
+    /// Get the current, adapted debounce time.
+    pub fn debounce_time(&self) -> M::Duration {
+        self.debouncer.debounce_time()
+    }
+    /// Get the lower bound of the debounce time.
+    pub fn min_time(&self) -> M::Duration {
+        self.min_time
+    }
+    /// Get the upper bound of the debounce time.
+    pub fn max_time(&self) -> M::Duration {
+        self.max_time
+    }
+    /// Get the factor applied to the observed bounce duration.
+    pub fn factor(&self) -> f32 {
+        self.factor
+    }
+}
+impl<M, T, V> AdaptiveDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy + PhysicalDuration,
+    M::Instant: Sub<Output = M::Duration>,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        let first_change = self.debouncer.unstable_since();
+        let state = self.debouncer.update(new_value);
+        if let (true, Some(first_change)) = (state.transitioned(), first_change) {
+            let bounce = (self.debouncer.last_change_time() - first_change).as_micros();
+            self.adapt(bounce);
+        }
+        state
+    }
+
+    fn adapt(&mut self, bounce: u64) {
+        let current = self.debouncer.debounce_time().as_micros();
+        let target = (bounce as f32 * self.factor) as u64;
+        let adapted = if target > current {
+            current + (target - current) / 2
+        } else {
+            current - (current - target) / 2
+        };
+        let adapted = adapted.clamp(self.min_time.as_micros(), self.max_time.as_micros());
+        self.debouncer
+            .set_debounce_time(M::Duration::from_micros(adapted));
+    }
+}
+impl<M, T, V> AdaptiveDebouncer<M, T, V>
+where
+    M: Monotonic,
+    V: Value<T = T>,
+    V::V: Copy,
+{
+    /// Reads the current stable value, if available. This does not update the internal state and just returns the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(all(test, feature = "fugit"))]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_longer_bounces_increase_window() {
+        run_test(|_| {
+            let mut debouncer =
+                AdaptiveDebouncer::<MockMonotonic, _>::new(false, 5.millis(), 20.millis(), 2.0);
+            let mut value = false;
+            let mut previous = debouncer.debounce_time();
+            for _ in 0..6 {
+                value = !value;
+                // bounce for 8ms before settling
+                for _ in 0..4 {
+                    debouncer.update(value);
+                    MockMonotonic::add(1.millis());
+                    debouncer.update(!value);
+                    MockMonotonic::add(1.millis());
+                }
+                debouncer.update(value);
+                MockMonotonic::add(debouncer.debounce_time());
+                assert!(debouncer.update(value).transitioned());
+                assert!(debouncer.debounce_time() >= previous);
+                previous = debouncer.debounce_time();
+            }
+            assert!((15_000..16_000).contains(&debouncer.debounce_time().ticks()));
+
+            for _ in 0..20 {
+                value = !value;
+                for _ in 0..10 {
+                    debouncer.update(value);
+                    MockMonotonic::add(1.millis());
+                    debouncer.update(!value);
+                    MockMonotonic::add(1.millis());
+                }
+                debouncer.update(value);
+                MockMonotonic::add(debouncer.debounce_time());
+                debouncer.update(value);
+            }
+            assert_eq!(debouncer.debounce_time().ticks(), 20_000);
+        });
+    }
+}
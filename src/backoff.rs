@@ -0,0 +1,158 @@
+use crate::{
+    InitializedValue, Monotonic, NonZeroDuration, PhysicalDuration, State, TimedDebouncer, Value,
+};
+
+/// Debouncer whose window backs off exponentially while the input is flaky.
+///
+/// Every transition that follows the previous one within `burst_interval` doubles the debounce
+/// window, up to `max_time`. Every `decay` the input stays stable halves it again, down to
+/// `min_time`. This suits intermittent connectors that only misbehave under vibration.
+///
+/// `min_time`, `burst_interval` and `decay` are [`NonZeroDuration`]s, since a zero window could
+/// never be doubled and a zero burst interval or decay would silently disable the back-off.
+pub struct BackoffDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    min_time: M::Duration,
+    max_time: M::Duration,
+    burst_interval: M::Duration,
+    decay: M::Duration,
+    last_transition: Option<M::Instant>,
+    calm_since: M::Instant,
+}
+
+impl<M, T> BackoffDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value. The window starts at `min_time`.
+    pub fn new_adaptive_backoff(
+        initial_value: T,
+        min_time: NonZeroDuration<M>,
+        max_time: M::Duration,
+        burst_interval: NonZeroDuration<M>,
+        decay: NonZeroDuration<M>,
+    ) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, min_time.get()),
+            min_time: min_time.get(),
+            max_time,
+            burst_interval: burst_interval.get(),
+            decay: decay.get(),
+            last_transition: None,
+            calm_since: M::ZERO,
+        }
+    }
+}
+impl<M: Monotonic, T, V: Value<T = T>> BackoffDebouncer<M, T, V>
+where
+    M::Duration: Copy,
+{
+    /// Get the debounce window that currently applies.
+    pub fn effective_window(&self) -> M::Duration {
+        self.debouncer.debounce_time()
+    }
+    /// Get the lower bound of the debounce window.
+    pub fn min_time(&self) -> M::Duration {
+        self.min_time
+    }
+    /// Get the upper bound of the debounce window.
+    pub fn max_time(&self) -> M::Duration {
+        self.max_time
+    }
+}
+impl<M, T, V> BackoffDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy + PhysicalDuration,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        let now = M::now();
+        self.decay_until(now);
+        let state = self.debouncer.update_at(new_value, now);
+        if state.transitioned() {
+            if let Some(last) = self.last_transition {
+                if now < last + self.burst_interval {
+                    let doubled = self.effective_window().as_micros().saturating_mul(2);
+                    self.set_window(doubled);
+                }
+            }
+            self.last_transition = Some(now);
+            self.calm_since = now;
+        }
+        state
+    }
+
+    fn decay_until(&mut self, now: M::Instant) {
+        if self.debouncer.is_settling() {
+            self.calm_since = now;
+            return;
+        }
+        while now >= self.calm_since + self.decay
+            && self.effective_window().as_micros() > self.min_time.as_micros()
+        {
+            let halved = self.effective_window().as_micros() / 2;
+            self.set_window(halved);
+            self.calm_since = self.calm_since + self.decay;
+        }
+    }
+
+    fn set_window(&mut self, micros: u64) {
+        let micros = micros.clamp(self.min_time.as_micros(), self.max_time.as_micros());
+        self.debouncer
+            .set_debounce_time(M::Duration::from_micros(micros));
+    }
+}
+impl<M, T, V> BackoffDebouncer<M, T, V>
+where
+    M: Monotonic,
+    V: Value<T = T>,
+    V::V: Copy,
+{
+    /// Reads the current stable value, if available. This does not update the internal state and just returns the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(all(test, feature = "fugit"))]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_burst_grows_and_stability_decays_window() {
+        run_test(|_| {
+            let mut debouncer = BackoffDebouncer::<MockMonotonic, _>::new_adaptive_backoff(
+                false,
+                NonZeroDuration::new(5.millis()).unwrap(),
+                40.millis(),
+                NonZeroDuration::new(100.millis()).unwrap(),
+                NonZeroDuration::new(500.millis()).unwrap(),
+            );
+            let mut value = false;
+            let mut windows = [0; 5];
+            for window in windows.iter_mut() {
+                value = !value;
+                debouncer.update(value);
+                MockMonotonic::add(debouncer.effective_window());
+                assert!(debouncer.update(value).transitioned());
+                *window = debouncer.effective_window().ticks();
+            }
+            assert_eq!(windows, [5_000, 10_000, 20_000, 40_000, 40_000]);
+
+            MockMonotonic::add(500.millis());
+            debouncer.update(value);
+            assert_eq!(debouncer.effective_window().ticks(), 20_000);
+            MockMonotonic::add(1_000.millis());
+            debouncer.update(value);
+            assert_eq!(debouncer.effective_window().ticks(), 5_000);
+        });
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{DebouncedInput, InitializedValue, Input, Monotonic, State};
+
+/// Debounces a tuple of heterogeneous inputs at once, preserving their individual types.
+///
+/// Implemented for tuples of 2 to 8 [`Input`]s.
+pub trait DebounceAll<M: Monotonic, T> {
+    /// Tuple of the resulting [`DebouncedInput`]s.
+    type Output;
+    /// Wraps every input of the tuple in a [`DebouncedInput`] with the same debounce time.
+    fn debounce_all(self, debounce_time: M::Duration) -> Self::Output;
+}
+
+/// Reads a tuple of [`DebouncedInput`]s at once.
+///
+/// Implemented for tuples of 2 to 8 [`DebouncedInput`]s.
+pub trait ReadAll {
+    /// Tuple of the states of all inputs.
+    type States;
+    /// Reads all inputs and returns their states.
+    fn read_all(&mut self) -> Self::States;
+}
+
+macro_rules! impl_tuple {
+    ($(($T:ident, $I:ident, $idx:tt)),+) => {
+        impl<M, $($T, $I),+> DebounceAll<M, ($($T,)+)> for ($($I,)+)
+        where
+            M: Monotonic,
+            M::Duration: Copy,
+            $($I: Input<$T>, $T: Copy + PartialEq,)+
+        {
+            type Output = ($(DebouncedInput<M, $T, $I>,)+);
+            fn debounce_all(self, debounce_time: M::Duration) -> Self::Output {
+                ($(DebouncedInput::new(self.$idx, debounce_time),)+)
+            }
+        }
+        impl<M, $($T, $I),+> ReadAll for ($(DebouncedInput<M, $T, $I>,)+)
+        where
+            M: Monotonic,
+            M::Duration: Copy,
+            $($I: Input<$T>, $T: Copy + PartialEq,)+
+        {
+            type States = ($(State<$T, InitializedValue<$T>>,)+);
+            fn read_all(&mut self) -> Self::States {
+                ($(self.$idx.read(),)+)
+            }
+        }
+    };
+}
+
+impl_tuple!((T0, I0, 0), (T1, I1, 1));
+impl_tuple!((T0, I0, 0), (T1, I1, 1), (T2, I2, 2));
+impl_tuple!((T0, I0, 0), (T1, I1, 1), (T2, I2, 2), (T3, I3, 3));
+impl_tuple!(
+    (T0, I0, 0),
+    (T1, I1, 1),
+    (T2, I2, 2),
+    (T3, I3, 3),
+    (T4, I4, 4)
+);
+impl_tuple!(
+    (T0, I0, 0),
+    (T1, I1, 1),
+    (T2, I2, 2),
+    (T3, I3, 3),
+    (T4, I4, 4),
+    (T5, I5, 5)
+);
+impl_tuple!(
+    (T0, I0, 0),
+    (T1, I1, 1),
+    (T2, I2, 2),
+    (T3, I3, 3),
+    (T4, I4, 4),
+    (T5, I5, 5),
+    (T6, I6, 6)
+);
+impl_tuple!(
+    (T0, I0, 0),
+    (T1, I1, 1),
+    (T2, I2, 2),
+    (T3, I3, 3),
+    (T4, I4, 4),
+    (T5, I5, 5),
+    (T6, I6, 6),
+    (T7, I7, 7)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    struct Button(bool);
+    impl Input<bool> for Button {
+        fn read(&mut self) -> bool {
+            self.0
+        }
+    }
+    struct Selector(u8);
+    impl Input<u8> for Selector {
+        fn read(&mut self) -> u8 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_read_all() {
+        run_test(|_| {
+            let mut bank: (DebouncedInput<MockMonotonic, _, _>, DebouncedInput<_, _, _>) =
+                (Button(true), Selector(3)).debounce_all(10.millis());
+            assert_eq!(
+                bank.read_all(),
+                (State::Stable { value: true }, State::Stable { value: 3 })
+            );
+        });
+    }
+}
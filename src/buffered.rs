@@ -0,0 +1,154 @@
+use crate::{DebouncedInput, InitializedValue, Input, Monotonic, State};
+
+/// Debounced input that decouples the sampling rate from the rate at which transitions are
+/// reported.
+///
+/// Every call to [`sample`](BufferedDebouncedInput::sample) updates the debouncer and stores a
+/// detected transition in a queue of up to `N` entries, which can then be drained at a slower
+/// cadence using [`poll_event`](BufferedDebouncedInput::poll_event).
+///
+/// If the queue is full when a new transition is detected the oldest transition is dropped, so
+/// the queue always ends with the current stable value.
+pub struct BufferedDebouncedInput<M: Monotonic, T: Copy, I, const N: usize> {
+    input: DebouncedInput<M, T, I>,
+    events: [Option<State<T, InitializedValue<T>>>; N],
+    head: usize,
+    len: usize,
+}
+
+impl<M, T, I, const N: usize> BufferedDebouncedInput<M, T, I, N>
+where
+    I: Input<T>,
+    M: Monotonic,
+    M::Duration: Copy,
+    T: Copy + PartialEq,
+{
+    /// Creates a new [`BufferedDebouncedInput`] by wrapping an [`Input`]
+    pub fn new(input: I, debounce_time: M::Duration) -> Self {
+        Self {
+            input: DebouncedInput::new(input, debounce_time),
+            events: [None; N],
+            head: 0,
+            len: 0,
+        }
+    }
+    /// Samples the input and queues a detected transition. Returns the current state.
+    pub fn sample(&mut self) -> State<T, InitializedValue<T>> {
+        let state = self.input.read();
+        if state.transitioned() && N > 0 {
+            if self.len == N {
+                // queue is full, drop the oldest transition
+                self.head = (self.head + 1) % N;
+                self.len -= 1;
+            }
+            self.events[(self.head + self.len) % N] = Some(state);
+            self.len += 1;
+        }
+        state
+    }
+}
+
+impl<M: Monotonic, T: Copy, I, const N: usize> BufferedDebouncedInput<M, T, I, N> {
+    /// Removes the oldest queued transition and returns it, if there is any.
+    /// The returned state is always a [`State::Transitioned`].
+    pub fn poll_event(&mut self) -> Option<State<T, InitializedValue<T>>> {
+        if self.len == 0 {
+            return None;
+        }
+        let event = self.events[self.head].take();
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        event
+    }
+    /// Returns the number of queued transitions.
+    pub fn pending_events(&self) -> usize {
+        self.len
+    }
+}
+
+impl<M, T, I, const N: usize> BufferedDebouncedInput<M, T, I, N>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: Copy + PartialEq,
+{
+    /// Read the last stable state of the input.
+    pub fn read_stable(&self) -> T {
+        self.input.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use core::cell::Cell;
+    use fugit::ExtU64;
+
+    struct Source<'a>(&'a Cell<u8>);
+    impl Input<u8> for Source<'_> {
+        fn read(&mut self) -> u8 {
+            self.0.get()
+        }
+    }
+
+    fn settle<I: Input<u8>, const N: usize>(
+        input: &mut BufferedDebouncedInput<MockMonotonic, u8, I, N>,
+        level: &Cell<u8>,
+        value: u8,
+    ) {
+        level.set(value);
+        input.sample();
+        MockMonotonic::add(6.millis());
+        assert!(input.sample().transitioned_to(&value));
+    }
+
+    fn transitioned_to(state: Option<State<u8, InitializedValue<u8>>>) -> Option<u8> {
+        match state {
+            Some(State::Transitioned { stable, .. }) => Some(stable),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_overflow_drops_oldest() {
+        run_test(|_| {
+            let level = Cell::new(0);
+            let mut input =
+                BufferedDebouncedInput::<MockMonotonic, _, _, 3>::new(Source(&level), 5.millis());
+            for value in 1..=5 {
+                settle(&mut input, &level, value);
+            }
+            assert_eq!(input.pending_events(), 3);
+            assert_eq!(transitioned_to(input.poll_event()), Some(3));
+            assert_eq!(transitioned_to(input.poll_event()), Some(4));
+            assert_eq!(transitioned_to(input.poll_event()), Some(5));
+            assert_eq!(input.poll_event(), None);
+            assert_eq!(input.pending_events(), 0);
+        });
+    }
+
+    #[test]
+    fn test_drain_in_fifo_order_across_wrap() {
+        run_test(|_| {
+            let level = Cell::new(0);
+            let mut input =
+                BufferedDebouncedInput::<MockMonotonic, _, _, 3>::new(Source(&level), 5.millis());
+            settle(&mut input, &level, 1);
+            settle(&mut input, &level, 2);
+            assert_eq!(transitioned_to(input.poll_event()), Some(1));
+            // wraps around the end of the buffer and overflows once
+            for value in 3..=5 {
+                settle(&mut input, &level, value);
+            }
+            assert_eq!(input.pending_events(), 3);
+            assert_eq!(transitioned_to(input.poll_event()), Some(3));
+            settle(&mut input, &level, 6);
+            assert_eq!(transitioned_to(input.poll_event()), Some(4));
+            assert_eq!(transitioned_to(input.poll_event()), Some(5));
+            assert_eq!(transitioned_to(input.poll_event()), Some(6));
+            assert_eq!(input.poll_event(), None);
+            assert_eq!(input.read_stable(), 6);
+        });
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    CountDebouncer, Debounce, HybridDebouncer, InitializedValue, Monotonic, State, TimedDebouncer,
+    UninitializedValue, Value,
+};
+
+/// Debouncing strategy selected by a [`DebouncerBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebouncerKind<D> {
+    /// A [`TimedDebouncer`] that accepts a value after `debounce_time`.
+    Time {
+        /// Time a new value has to stay before it becomes stable.
+        debounce_time: D,
+    },
+    /// A [`CountDebouncer`] that accepts a value after `required` consecutive samples.
+    Count {
+        /// Number of consecutive samples of a new value required before it becomes stable.
+        required: usize,
+    },
+    /// A [`HybridDebouncer`] that requires both `debounce_time` and `min_samples`.
+    Hybrid {
+        /// Time a new value has to stay before it becomes stable.
+        debounce_time: D,
+        /// Number of samples of a new value required before it becomes stable.
+        min_samples: u32,
+    },
+}
+
+/// Debouncer of any of the strategies of [`DebouncerKind`], as built by a [`DebouncerBuilder`].
+///
+/// This implements [`Debounce`], so application code can use it without knowing the strategy.
+pub enum AnyDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    /// Time based strategy.
+    Timed(TimedDebouncer<M, T, V>),
+    /// Count based strategy.
+    Count(CountDebouncer<T, V>),
+    /// Time and count based strategy.
+    Hybrid(HybridDebouncer<M, T, V>),
+}
+
+/// Builder that creates a debouncer of a strategy chosen at runtime, e.g. from a configuration.
+///
+/// ```ignore
+/// let kind = match config.strategy {
+///     0 => DebouncerKind::Time { debounce_time: 10.millis() },
+///     _ => DebouncerKind::Count { required: 3 },
+/// };
+/// let mut debouncer = DebouncerBuilder::<Mono>::new(kind).build(false);
+/// debouncer.update(true);
+/// ```
+pub struct DebouncerBuilder<M: Monotonic> {
+    kind: DebouncerKind<M::Duration>,
+}
+
+impl<M: Monotonic> DebouncerBuilder<M>
+where
+    M::Duration: Copy,
+{
+    /// Creates a new builder for the given strategy.
+    pub fn new(kind: DebouncerKind<M::Duration>) -> Self {
+        Self { kind }
+    }
+    /// Changes the strategy.
+    pub fn kind(mut self, kind: DebouncerKind<M::Duration>) -> Self {
+        self.kind = kind;
+        self
+    }
+    /// Builds a debouncer with a known initial value.
+    pub fn build<T: Copy>(self, initial_value: T) -> AnyDebouncer<M, T> {
+        match self.kind {
+            DebouncerKind::Time { debounce_time } => {
+                AnyDebouncer::Timed(TimedDebouncer::new(initial_value, debounce_time))
+            }
+            DebouncerKind::Count { required } => {
+                AnyDebouncer::Count(CountDebouncer::new(initial_value, required))
+            }
+            DebouncerKind::Hybrid {
+                debounce_time,
+                min_samples,
+            } => AnyDebouncer::Hybrid(HybridDebouncer::new(
+                initial_value,
+                debounce_time,
+                min_samples,
+            )),
+        }
+    }
+    /// Builds a debouncer that starts with an unkown state.
+    pub fn build_unknown<T: Copy>(self) -> AnyDebouncer<M, T, UninitializedValue<T>> {
+        match self.kind {
+            DebouncerKind::Time { debounce_time } => {
+                AnyDebouncer::Timed(TimedDebouncer::new_unknown(debounce_time))
+            }
+            DebouncerKind::Count { required } => {
+                AnyDebouncer::Count(CountDebouncer::new_unknown(required))
+            }
+            DebouncerKind::Hybrid {
+                debounce_time,
+                min_samples,
+            } => AnyDebouncer::Hybrid(HybridDebouncer::new_unknown(debounce_time, min_samples)),
+        }
+    }
+}
+
+impl<M, T, V> Debounce<T> for AnyDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    type Value = V;
+    fn update(&mut self, new_value: T) -> State<T, V> {
+        match self {
+            AnyDebouncer::Timed(debouncer) => debouncer.update(new_value),
+            AnyDebouncer::Count(debouncer) => debouncer.update(new_value),
+            AnyDebouncer::Hybrid(debouncer) => debouncer.update(new_value),
+        }
+    }
+    fn stable(&self) -> V::V {
+        match self {
+            AnyDebouncer::Timed(debouncer) => debouncer.read_stable(),
+            AnyDebouncer::Count(debouncer) => debouncer.read_stable(),
+            AnyDebouncer::Hybrid(debouncer) => debouncer.read_stable(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    /// Bounces twice, then holds `true` for 11ms over 4 polls.
+    fn bounce(debouncer: &mut impl Debounce<bool, Value = InitializedValue<bool>>) -> bool {
+        for value in [true, false, true, false] {
+            assert!(!debouncer.update(value).transitioned());
+            MockMonotonic::add(1.millis());
+        }
+        let mut transitioned = false;
+        for _ in 0..4 {
+            transitioned |= debouncer.update(true).transitioned();
+            MockMonotonic::add(4.millis());
+        }
+        transitioned
+    }
+
+    #[test]
+    fn test_each_kind() {
+        run_test(|_| {
+            let kinds = [
+                DebouncerKind::Time {
+                    debounce_time: 10.millis(),
+                },
+                DebouncerKind::Count { required: 3 },
+                DebouncerKind::Hybrid {
+                    debounce_time: 10.millis(),
+                    min_samples: 3,
+                },
+            ];
+            for kind in kinds {
+                let mut debouncer = DebouncerBuilder::<MockMonotonic>::new(kind).build(false);
+                assert!(bounce(&mut debouncer));
+                assert!(debouncer.stable());
+            }
+        });
+    }
+
+    #[test]
+    fn test_build_unknown() {
+        let mut debouncer = DebouncerBuilder::<MockMonotonic>::new(DebouncerKind::Time {
+            debounce_time: 10.millis(),
+        })
+        .kind(DebouncerKind::Count { required: 2 })
+        .build_unknown::<u8>();
+        assert!(matches!(debouncer, AnyDebouncer::Count(_)));
+        debouncer.update(1);
+        assert_eq!(debouncer.update(1), State::Initialized { value: 1 });
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    InitializedValue, Monotonic, Outcome, State, TimedDebouncer, UninitializedValue, Value,
+};
+
+/// Debouncer that remembers what its last update returned and did.
+///
+/// This keeps the [`State`] and the [`Outcome`] of the last update, so several code paths can read
+/// the latest result without coordinating who calls [`CachedDebouncer::update`], and the instant
+/// of the last transition for timestamping events.
+pub struct CachedDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    last_state: State<T, V>,
+    last_outcome: Outcome,
+    transitioned_at: Option<M::Instant>,
+}
+
+impl<M, T> CachedDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value.
+    pub fn new(initial_value: T, debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            last_state: State::Stable {
+                value: initial_value,
+            },
+            last_outcome: Outcome::NoChange,
+            transitioned_at: None,
+        }
+    }
+}
+impl<M, T> CachedDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state.
+    pub fn new_unknown(debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            last_state: State::Unstable {
+                stable: None,
+                most_recent: None,
+            },
+            last_outcome: Outcome::NoChange,
+            transitioned_at: None,
+        }
+    }
+}
+impl<M, T, V> CachedDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        let now = M::now();
+        let (state, outcome) = self.debouncer.update_outcome(new_value, true, now);
+        self.last_state = state;
+        self.last_outcome = outcome;
+        self.transitioned_at = state.transitioned().then(|| self.debouncer.settled_at(now));
+        state
+    }
+    /// Updates the debouncer state with a new value and returns the current state together with
+    /// a flag indicating whether it differs from the state returned by the previous update.
+    ///
+    /// Two states are considered different if their variants or any of their values differ.
+    /// This means a [`State::Transitioned`] is always reported as a change, and so is the
+    /// [`State::Stable`] following it, even though the stable value stayed the same.
+    pub fn update_delta(&mut self, new_value: T) -> (State<T, V>, bool)
+    where
+        V: PartialEq,
+        V::V: PartialEq,
+    {
+        let previous = self.last_state;
+        let state = self.update(new_value);
+        (state, state != previous)
+    }
+    /// Returns the state returned by the last update, without reading the clock or advancing the
+    /// debouncer.
+    pub fn last_state(&self) -> State<T, V> {
+        self.last_state
+    }
+    /// Returns what the last update did, without the values carried by [`State`].
+    pub fn last_outcome(&self) -> Outcome {
+        self.last_outcome
+    }
+    /// Returns the instant the value became stable, if the last update transitioned.
+    ///
+    /// This is the theoretical settle time, i.e. the time of the last change plus the debounce
+    /// time, not the time of the poll that detected the transition. Events can therefore be
+    /// timestamped independently of the polling rate. If the transition was forced earlier by the
+    /// maximum unstable time, it is the time of that update.
+    pub fn transition_instant(&self) -> Option<M::Instant> {
+        self.transitioned_at
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_update_delta() {
+        run_test(|_| {
+            let mut debouncer = CachedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            assert!(!debouncer.update_delta(false).1);
+            assert!(debouncer.update_delta(true).1);
+            assert!(!debouncer.update_delta(true).1);
+            MockMonotonic::add(11.millis());
+            assert!(debouncer.update_delta(true).1);
+            assert_eq!(
+                debouncer.update_delta(true),
+                (State::Stable { value: true }, true)
+            );
+            assert_eq!(
+                debouncer.update_delta(true),
+                (State::Stable { value: true }, false)
+            );
+        });
+    }
+
+    #[test]
+    fn test_last_state() {
+        run_test(|_| {
+            let mut debouncer = CachedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            assert_eq!(debouncer.last_state(), State::Stable { value: false });
+            let state = debouncer.update(true);
+            assert_eq!(debouncer.last_state(), state);
+            MockMonotonic::add(11.millis());
+            // the cached state does not advance with the clock
+            assert_eq!(
+                debouncer.last_state(),
+                State::Unstable {
+                    stable: false,
+                    most_recent: true
+                }
+            );
+            let state = debouncer.update(true);
+            assert!(state.transitioned());
+            assert_eq!(debouncer.last_state(), state);
+        });
+    }
+
+    #[test]
+    fn test_last_outcome() {
+        run_test(|_| {
+            let mut debouncer = CachedDebouncer::<MockMonotonic, u8>::new(0, 10.millis());
+            let mut outcome = |sample| {
+                debouncer.update(sample);
+                debouncer.last_outcome()
+            };
+            assert_eq!(outcome(0), Outcome::NoChange);
+            assert_eq!(outcome(1), Outcome::WindowStarted);
+            assert_eq!(outcome(1), Outcome::NoChange);
+            assert_eq!(outcome(2), Outcome::WindowRestarted);
+            assert_eq!(outcome(0), Outcome::NoiseRejected);
+            assert_eq!(outcome(2), Outcome::WindowStarted);
+            MockMonotonic::add(11.millis());
+            assert_eq!(outcome(2), Outcome::Transitioned);
+            assert_eq!(outcome(2), Outcome::NoChange);
+        });
+    }
+
+    #[test]
+    fn test_transition_instant() {
+        run_test(|_| {
+            let mut debouncer = CachedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            MockMonotonic::add(1.millis());
+            debouncer.update(true);
+            assert_eq!(debouncer.transition_instant(), None);
+            MockMonotonic::add(25.millis());
+            assert!(debouncer.update(true).transitioned());
+            assert_eq!(debouncer.transition_instant().unwrap().ticks(), 11_000);
+            debouncer.update(true);
+            assert_eq!(debouncer.transition_instant(), None);
+        });
+    }
+}
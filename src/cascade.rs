@@ -0,0 +1,123 @@
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Two debouncers in series for extremely noisy inputs.
+///
+/// The raw input is debounced with the `short` window first, which removes fast glitches. Its
+/// stable value is then debounced again with the `long` window, and the state of this final stage
+/// is reported. Glitches shorter than `short` never reach the second stage, so they do not restart
+/// its window. Glitches that pass the first stage are rejected by the second stage if they are
+/// shorter than `long`. A real change is reported after about `short + long`.
+pub struct Cascade<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    first: TimedDebouncer<M, T, V>,
+    second: TimedDebouncer<M, T, V>,
+}
+
+impl<M, T> Cascade<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new cascade with a known initial value.
+    pub fn new(initial_value: T, short: M::Duration, long: M::Duration) -> Self {
+        Self {
+            first: TimedDebouncer::new(initial_value, short),
+            second: TimedDebouncer::new(initial_value, long),
+        }
+    }
+}
+impl<M, T> Cascade<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new cascade that starts with an unkown state.
+    pub fn new_unknown(short: M::Duration, long: M::Duration) -> Self {
+        Self {
+            first: TimedDebouncer::new_unknown(short),
+            second: TimedDebouncer::new_unknown(long),
+        }
+    }
+}
+impl<M: Monotonic, T, V: Value<T = T>> Cascade<M, T, V> {
+    /// Returns the first stage, which debounces the raw input with the short window.
+    pub fn first_stage(&self) -> &TimedDebouncer<M, T, V> {
+        &self.first
+    }
+    /// Returns the second stage, which debounces the output of the first stage with the long
+    /// window.
+    pub fn second_stage(&self) -> &TimedDebouncer<M, T, V> {
+        &self.second
+    }
+}
+impl<M, T, V> Cascade<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates both stages with a new value and returns the state of the second stage.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        self.first.update(new_value);
+        match self.first.settled_value() {
+            Some(value) => self.second.update(value),
+            None => self.second.read(),
+        }
+    }
+}
+impl<M, T, V> Cascade<M, T, V>
+where
+    M: Monotonic,
+    V: Value<T = T>,
+    V::V: Copy,
+{
+    /// Reads the stable value of the second stage. This does not update the internal state.
+    pub fn read_stable(&self) -> V::V {
+        self.second.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_medium_glitch() {
+        run_test(|_| {
+            let mut single = TimedDebouncer::<MockMonotonic, _>::new(false, 5.millis());
+            let mut cascade = Cascade::<MockMonotonic, _>::new(false, 5.millis(), 20.millis());
+            let (mut single_changed, mut cascade_changed) = (false, false);
+            // a 12ms glitch is longer than the short window, but shorter than the long one
+            for value in [true; 12].into_iter().chain([false; 12]) {
+                single_changed |= single.update(value).transitioned();
+                cascade_changed |= cascade.update(value).transitioned();
+                MockMonotonic::add(1.millis());
+            }
+            for _ in 0..30 {
+                cascade_changed |= cascade.update(false).transitioned();
+                MockMonotonic::add(1.millis());
+            }
+            assert!(single_changed);
+            assert!(!cascade_changed);
+            assert!(!cascade.read_stable());
+        });
+    }
+
+    #[test]
+    fn test_real_change() {
+        run_test(|_| {
+            let mut cascade = Cascade::<MockMonotonic, _>::new(false, 5.millis(), 20.millis());
+            for _ in 0..25 {
+                assert!(!cascade.update(true).transitioned());
+                MockMonotonic::add(1.millis());
+            }
+            assert!(cascade.update(true).transitioned());
+            assert!(cascade.read_stable());
+        });
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{Monotonic, TimedDebouncer};
+
+/// Debounces `N` boolean lines independently and reports which of them changed in each update.
+///
+/// This is useful to validate inputs like gray-code encoded selectors, where a valid step changes
+/// exactly one line. Multiple lines changing in the same update are flagged as suspect.
+pub struct ChangeCounter<M: Monotonic, const N: usize> {
+    debouncers: [TimedDebouncer<M, bool>; N],
+}
+
+/// Lines that transitioned to a new stable value during a single update of a [`ChangeCounter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Changes<const N: usize> {
+    changed: [bool; N],
+}
+
+impl<const N: usize> Changes<N> {
+    /// Returns the number of lines that changed.
+    pub fn count(&self) -> usize {
+        self.changed.iter().filter(|changed| **changed).count()
+    }
+    /// Checks if the line with the given index changed.
+    pub fn changed(&self, line: usize) -> bool {
+        self.changed[line]
+    }
+    /// Returns for every line whether it changed.
+    pub fn lines(&self) -> [bool; N] {
+        self.changed
+    }
+    /// Checks if more than one line changed at the same time.
+    pub fn is_suspect(&self) -> bool {
+        self.count() > 1
+    }
+}
+
+impl<M, const N: usize> ChangeCounter<M, N>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+{
+    /// Creates a new [`ChangeCounter`] with known initial values.
+    pub fn new(initial_values: [bool; N], debounce_time: M::Duration) -> Self {
+        Self {
+            debouncers: core::array::from_fn(|i| {
+                TimedDebouncer::new(initial_values[i], debounce_time)
+            }),
+        }
+    }
+    /// Updates all lines with new values and returns which of them transitioned.
+    pub fn update(&mut self, values: [bool; N]) -> Changes<N> {
+        let mut changed = [false; N];
+        for ((debouncer, value), changed) in self
+            .debouncers
+            .iter_mut()
+            .zip(values)
+            .zip(changed.iter_mut())
+        {
+            *changed = debouncer.update(value).transitioned();
+        }
+        Changes { changed }
+    }
+    /// Read the last stable values of all lines.
+    pub fn read_stable(&self) -> [bool; N] {
+        core::array::from_fn(|i| self.debouncers[i].read_stable())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_double_change_is_suspect() {
+        run_test(|_| {
+            let mut lines = ChangeCounter::<MockMonotonic, 2>::new([false, false], 10.millis());
+            lines.update([true, false]);
+            MockMonotonic::add(11.millis());
+            let changes = lines.update([true, false]);
+            assert_eq!(changes.count(), 1);
+            assert!(changes.changed(0));
+            assert!(!changes.is_suspect());
+
+            lines.update([false, true]);
+            MockMonotonic::add(11.millis());
+            let changes = lines.update([false, true]);
+            assert_eq!(changes.lines(), [true, true]);
+            assert!(changes.is_suspect());
+            assert_eq!(lines.read_stable(), [false, true]);
+        });
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Debounces `N` correlated channels jointly with one shared window.
+///
+/// A change on any channel restarts the window for all of them, so the array only reports a new
+/// stable set of values once all channels were quiet together for the debounce time. This suits
+/// inputs that must be read atomically, like a parallel bus. Use a
+/// [`ChangeCounter`](crate::ChangeCounter) to debounce the channels independently instead.
+pub struct CoherentArray<M: Monotonic, T, const N: usize, V = InitializedValue<[T; N]>>
+where
+    V: Value<T = [T; N]>,
+{
+    debouncer: TimedDebouncer<M, [T; N], V>,
+}
+
+impl<M, T, const N: usize> CoherentArray<M, T, N, InitializedValue<[T; N]>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new [`CoherentArray`] with known initial values.
+    pub fn new(initial_values: [T; N], debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_values, debounce_time),
+        }
+    }
+}
+impl<M, T, const N: usize> CoherentArray<M, T, N, UninitializedValue<[T; N]>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new [`CoherentArray`] whose values are unknown until they became stable.
+    pub fn new_unknown(debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+        }
+    }
+}
+impl<M, T, const N: usize, V> CoherentArray<M, T, N, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = [T; N]> + Copy + From<[T; N]>,
+    V::V: Copy,
+{
+    /// Updates all channels at once and returns the joint state.
+    pub fn update(&mut self, values: [T; N]) -> State<[T; N], V> {
+        self.debouncer.update(values)
+    }
+    /// Read the last joint stable values.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_shared_window() {
+        run_test(|_| {
+            let mut bus = CoherentArray::<MockMonotonic, bool, 2>::new([false, false], 10.millis());
+            bus.update([true, false]);
+            MockMonotonic::add(6.millis());
+            // the second channel settles later and restarts the window for both
+            bus.update([true, true]);
+            MockMonotonic::add(6.millis());
+            assert!(!bus.update([true, true]).transitioned());
+            assert_eq!(bus.read_stable(), [false, false]);
+            MockMonotonic::add(5.millis());
+            assert!(bus.update([true, true]).transitioned());
+            assert_eq!(bus.read_stable(), [true, true]);
+        });
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer};
+
+/// Reduction applied by a [`CombinedDebouncer`] to the debounced channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reduction {
+    /// `true` if any channel is `true`.
+    Any,
+    /// `true` if all channels are `true`.
+    All,
+    /// `true` if more than half of the channels are `true`.
+    Majority,
+}
+
+impl Reduction {
+    /// Applies the reduction to the given values.
+    pub fn reduce(&self, values: &[bool]) -> bool {
+        match self {
+            Reduction::Any => values.iter().any(|v| *v),
+            Reduction::All => values.iter().all(|v| *v),
+            Reduction::Majority => values.iter().filter(|v| **v).count() * 2 > values.len(),
+        }
+    }
+}
+
+/// Debounces `N` boolean channels independently and combines them into a single value.
+///
+/// Unlike debouncing a pre-combined value, the noise of each channel is handled on its own before
+/// the [`Reduction`] is applied to the stable channel values.
+pub struct CombinedDebouncer<M: Monotonic, const N: usize> {
+    debouncers: [TimedDebouncer<M, bool>; N],
+    states: [State<bool, InitializedValue<bool>>; N],
+    reduction: Reduction,
+}
+
+impl<M, const N: usize> CombinedDebouncer<M, N>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+{
+    /// Creates a new [`CombinedDebouncer`] with known initial values.
+    pub fn new(
+        initial_values: [bool; N],
+        debounce_time: M::Duration,
+        reduction: Reduction,
+    ) -> Self {
+        Self {
+            debouncers: core::array::from_fn(|i| {
+                TimedDebouncer::new(initial_values[i], debounce_time)
+            }),
+            states: core::array::from_fn(|i| State::Stable {
+                value: initial_values[i],
+            }),
+            reduction,
+        }
+    }
+    /// Updates all channels with new values and returns the state of the combined value.
+    ///
+    /// The combined value is unstable while the reduction of the most recent channel values
+    /// differs from the reduction of the stable channel values.
+    pub fn update(&mut self, values: [bool; N]) -> State<bool, InitializedValue<bool>> {
+        let previous = self.read_stable();
+        for ((debouncer, value), state) in self
+            .debouncers
+            .iter_mut()
+            .zip(values)
+            .zip(self.states.iter_mut())
+        {
+            *state = debouncer.update(value);
+        }
+        let stable = self.read_stable();
+        let most_recent = self.reduction.reduce(&core::array::from_fn::<_, N, _>(|i| {
+            self.states[i].most_recent()
+        }));
+        if stable != previous {
+            State::Transitioned {
+                stable,
+                previous_stable: previous,
+            }
+        } else if most_recent != stable {
+            State::Unstable {
+                stable,
+                most_recent,
+            }
+        } else {
+            State::Stable { value: stable }
+        }
+    }
+    /// Returns the states of the individual channels from the last update.
+    pub fn channel_states(&self) -> [State<bool, InitializedValue<bool>>; N] {
+        self.states
+    }
+    /// Read the last stable combined value.
+    pub fn read_stable(&self) -> bool {
+        self.reduction.reduce(&core::array::from_fn::<_, N, _>(|i| {
+            self.debouncers[i].read_stable()
+        }))
+    }
+    /// Returns the [`Reduction`].
+    pub fn reduction(&self) -> Reduction {
+        self.reduction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_majority() {
+        run_test(|_| {
+            let mut doors = CombinedDebouncer::<MockMonotonic, 3>::new(
+                [false; 3],
+                10.millis(),
+                Reduction::Majority,
+            );
+            assert_eq!(
+                doors.update([true, false, false]),
+                State::Stable { value: false }
+            );
+            assert_eq!(
+                doors.update([true, true, false]),
+                State::Unstable {
+                    stable: false,
+                    most_recent: true
+                }
+            );
+            MockMonotonic::add(11.millis());
+            assert!(doors.update([true, true, false]).transitioned());
+            assert!(doors.channel_states()[0].stable());
+            assert!(!doors.channel_states()[2].stable());
+        });
+    }
+}
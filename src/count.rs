@@ -0,0 +1,263 @@
+use core::ops::Mul;
+
+use crate::{Debounce, InitializedValue, State, UninitializedValue, Value};
+
+/// Debouncer that accepts a new value after a number of consecutive identical samples.
+///
+/// Unlike [`TimedDebouncer`](crate::TimedDebouncer) this does not depend on time at all, so it
+/// needs no [`Monotonic`](crate::Monotonic) and its behaviour depends on the polling rate.
+///
+/// The number of required samples can be overridden for up to `K` values, see
+/// [`CountDebouncer::with_counts`].
+pub struct CountDebouncer<T, V: Value<T = T> = InitializedValue<T>, const K: usize = 0> {
+    last_stable: V,
+    candidate: Option<T>,
+    count: usize,
+    required: usize,
+    count_overrides: [(T, usize); K],
+}
+
+impl<T: Copy> CountDebouncer<T, InitializedValue<T>> {
+    /// Creates a new Debouncer with a known initial value that requires `required` consecutive
+    /// samples of a new value. A value of `0` is treated like `1`.
+    pub fn new(initial_value: T, required: usize) -> Self {
+        Self {
+            last_stable: InitializedValue::new(initial_value),
+            candidate: None,
+            count: 0,
+            required: required.max(1),
+            count_overrides: [],
+        }
+    }
+}
+impl<T: Copy> CountDebouncer<T, UninitializedValue<T>> {
+    /// Creates a new Debouncer that starts with an unkown state and requires `required`
+    /// consecutive samples of a new value. A value of `0` is treated like `1`.
+    pub fn new_unknown(required: usize) -> Self {
+        Self {
+            last_stable: Default::default(),
+            candidate: None,
+            count: 0,
+            required: required.max(1),
+            count_overrides: [],
+        }
+    }
+}
+impl CountDebouncer<bool, InitializedValue<bool>, 1> {
+    /// Creates a new Debouncer for a boolean input with a known initial value that requires
+    /// `press_count` consecutive `true` samples to assert pressed and `release_count` consecutive
+    /// `false` samples to assert released.
+    pub fn new_asymmetric(initial_value: bool, press_count: usize, release_count: usize) -> Self {
+        CountDebouncer::new(initial_value, press_count).with_count_for(false, release_count)
+    }
+}
+impl CountDebouncer<bool, UninitializedValue<bool>, 1> {
+    /// Creates a new Debouncer for a boolean input that starts with an unkown state and requires
+    /// `press_count` consecutive `true` samples to assert pressed and `release_count` consecutive
+    /// `false` samples to assert released.
+    pub fn new_unknown_asymmetric(press_count: usize, release_count: usize) -> Self {
+        CountDebouncer::new_unknown(press_count).with_count_for(false, release_count)
+    }
+}
+impl<T, V, const K: usize> CountDebouncer<T, V, K>
+where
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        if self.last_stable.try_get() == Some(new_value) {
+            self.candidate = None;
+            self.count = 0;
+            return State::Stable { value: new_value };
+        }
+        if self.candidate == Some(new_value) {
+            self.count = self.count.saturating_add(1);
+        } else {
+            self.candidate = Some(new_value);
+            self.count = 1;
+        }
+        if self.count < self.required_for(new_value) {
+            return State::Unstable {
+                stable: self.last_stable.get(),
+                most_recent: V::wrap(new_value),
+            };
+        }
+        let previous_stable = self.last_stable;
+        self.last_stable = new_value.into();
+        self.candidate = None;
+        self.count = 0;
+        if previous_stable.try_get().is_some() {
+            State::Transitioned {
+                stable: new_value,
+                previous_stable: previous_stable.get(),
+            }
+        } else {
+            State::Initialized { value: new_value }
+        }
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.last_stable.get()
+    }
+}
+impl<T: PartialEq + Copy, V: Value<T = T>> CountDebouncer<T, V> {
+    /// Overrides the number of consecutive samples required before `value` becomes stable. All
+    /// other values keep using the count passed to the constructor. A value of `0` is treated like
+    /// `1`.
+    ///
+    /// This is only available without overrides, so it cannot replace an earlier override by
+    /// accident. Use [`CountDebouncer::with_counts`] to override the count for several values.
+    pub fn with_count_for(self, value: T, required: usize) -> CountDebouncer<T, V, 1> {
+        self.with_counts([(value, required)])
+    }
+}
+impl<T: PartialEq + Copy, V: Value<T = T>, const K: usize> CountDebouncer<T, V, K> {
+    /// Replaces the count overrides with `counts`, a list of values and the number of consecutive
+    /// samples required before each of them becomes stable. All other values keep using the count
+    /// passed to the constructor. A count of `0` is treated like `1`. If a value is listed more
+    /// than once, the first entry applies.
+    pub fn with_counts<const N: usize>(self, counts: [(T, usize); N]) -> CountDebouncer<T, V, N> {
+        CountDebouncer {
+            last_stable: self.last_stable,
+            candidate: self.candidate,
+            count: self.count,
+            required: self.required,
+            count_overrides: counts.map(|(value, required)| (value, required.max(1))),
+        }
+    }
+    /// Returns the number of consecutive samples required before `value` becomes stable.
+    pub fn required_for(&self, value: T) -> usize {
+        self.count_overrides
+            .iter()
+            .find(|(v, _)| *v == value)
+            .map_or(self.required, |&(_, required)| required)
+    }
+    /// Returns the number of further samples of the pending value needed for a transition, or
+    /// zero if no value is pending.
+    ///
+    /// This is also zero if the required count was lowered below the samples already seen, e.g.
+    /// by [`CountDebouncer::with_counts`]. The next sample of the pending value then transitions.
+    pub fn remaining_count(&self) -> usize {
+        match self.candidate {
+            Some(candidate) => self.required_for(candidate).saturating_sub(self.count),
+            None => 0,
+        }
+    }
+    /// Estimates the time until the pending value becomes stable, if the debouncer is updated
+    /// every `interval`. This is zero if no value is pending.
+    pub fn estimated_time_remaining<D: Mul<u32, Output = D>>(&self, interval: D) -> D {
+        let remaining = u32::try_from(self.remaining_count()).unwrap_or(u32::MAX);
+        interval * remaining
+    }
+    /// Returns how many consecutive samples of the pending value were seen so far and how many are
+    /// required, e.g. `(2, 3)` for "2 of 3 confirmations".
+    pub fn progress_count(&self) -> (usize, usize) {
+        let required = self
+            .candidate
+            .map_or(self.required, |c| self.required_for(c));
+        (self.count, required)
+    }
+}
+
+impl<T, V, const K: usize> Debounce<T> for CountDebouncer<T, V, K>
+where
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    type Value = V;
+    fn update(&mut self, new_value: T) -> State<T, V> {
+        self.update(new_value)
+    }
+    fn stable(&self) -> V::V {
+        self.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_count() {
+        let mut debouncer = CountDebouncer::new(false, 3);
+        assert_eq!(debouncer.remaining_count(), 0);
+        debouncer.update(true);
+        debouncer.update(true);
+        assert_eq!(debouncer.progress_count(), (2, 3));
+        assert_eq!(debouncer.remaining_count(), 1);
+        debouncer.update(false);
+        assert_eq!(debouncer.progress_count(), (0, 3));
+        debouncer.update(true);
+        debouncer.update(true);
+        assert!(debouncer.update(true).transitioned_to(&true));
+        assert_eq!(debouncer.remaining_count(), 0);
+    }
+
+    #[test]
+    fn test_asymmetric_press_count() {
+        let mut debouncer = CountDebouncer::new_asymmetric(false, 3, 2);
+        assert!(!debouncer.update(true).transitioned());
+        assert_eq!(debouncer.progress_count(), (1, 3));
+        assert!(!debouncer.update(true).transitioned());
+        assert!(debouncer.update(true).transitioned_to(&true));
+    }
+
+    #[test]
+    fn test_asymmetric_release_count() {
+        let mut debouncer = CountDebouncer::new_asymmetric(true, 3, 2);
+        assert!(!debouncer.update(false).transitioned());
+        assert_eq!(debouncer.progress_count(), (1, 2));
+        assert_eq!(debouncer.remaining_count(), 1);
+        assert!(debouncer.update(false).transitioned_to(&false));
+    }
+
+    #[test]
+    fn test_count_for_generic_value() {
+        let mut debouncer = CountDebouncer::new_unknown(1).with_count_for(2u8, 3);
+        assert_eq!(debouncer.update(1), State::Initialized { value: 1 });
+        assert!(!debouncer.update(2).transitioned());
+        assert!(!debouncer.update(2).transitioned());
+        assert!(debouncer.update(2).transitioned());
+        assert!(debouncer.update(3).transitioned());
+    }
+
+    #[test]
+    fn test_counts_for_several_values() {
+        let mut debouncer = CountDebouncer::new(0u8, 1).with_counts([(2, 3), (3, 2), (2, 5)]);
+        assert_eq!(debouncer.required_for(1), 1);
+        assert_eq!(debouncer.required_for(2), 3);
+        assert_eq!(debouncer.required_for(3), 2);
+        assert!(debouncer.update(1).transitioned());
+        assert!(!debouncer.update(3).transitioned());
+        assert!(debouncer.update(3).transitioned());
+        assert!(!debouncer.update(2).transitioned());
+        assert!(!debouncer.update(2).transitioned());
+        assert!(debouncer.update(2).transitioned());
+    }
+
+    #[test]
+    fn test_estimated_time_remaining() {
+        let interval = fugit::MillisDurationU64::millis(10);
+        let mut debouncer = CountDebouncer::new(false, 5);
+        assert_eq!(debouncer.estimated_time_remaining(interval).to_millis(), 0);
+        debouncer.update(true);
+        debouncer.update(true);
+        assert_eq!(debouncer.estimated_time_remaining(interval).to_millis(), 30);
+        assert_eq!(debouncer.estimated_time_remaining(7u32), 21);
+    }
+
+    #[test]
+    fn test_lowered_count_keeps_progress() {
+        let mut debouncer = CountDebouncer::new(false, 5);
+        debouncer.update(true);
+        debouncer.update(true);
+        debouncer.update(true);
+        let mut debouncer = debouncer.with_count_for(true, 2);
+        assert_eq!(debouncer.remaining_count(), 0);
+        assert_eq!(debouncer.progress_count(), (3, 2));
+        assert!(debouncer.update(true).transitioned_to(&true));
+    }
+}
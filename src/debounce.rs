@@ -0,0 +1,241 @@
+use core::ops::Sub;
+
+use crate::{
+    AdaptiveDebouncer, BackoffDebouncer, CachedDebouncer, Cascade, FixedDebouncer,
+    HistoryDebouncer, HybridDebouncer, InitializedValue, JumpDebouncer, LatchingDebouncer,
+    MinHoldDebouncer, Monotonic, PhysicalDuration, PulseWidthDebouncer, RangeDebouncer, State,
+    StatsDebouncer, StickyDebouncer, StuckDebouncer, TableDebouncer, TickDuration, TimedDebouncer,
+    TransitionObserver, UptimeDebouncer, ValidatedDebouncer, Value, WarmupDebouncer,
+};
+
+/// Common interface of all debouncers of single values of type `T`.
+///
+/// This allows writing application code that is generic over the debouncing strategy. The trait
+/// is object safe, so the strategy can also be selected at runtime with `dyn Debounce<T, Value = V>`.
+pub trait Debounce<T> {
+    /// Type of the debounced value, either [`InitializedValue`] or
+    /// [`UninitializedValue`](crate::UninitializedValue).
+    type Value: Value<T = T>;
+    /// Updates the debouncer with a new value and returns the current state.
+    fn update(&mut self, new_value: T) -> State<T, Self::Value>;
+    /// Returns the current stable value.
+    fn stable(&self) -> <Self::Value as Value>::V;
+}
+
+/// Implements [`Debounce`] for debouncers with inherent `update` and `read_stable` methods.
+///
+/// Each entry lists the generic parameters in brackets, the debouncer type, its value type and the
+/// bounds of the impl in braces.
+macro_rules! impl_debounce {
+    ($([$($generics:tt)*] $debouncer:ty => $value:ty { $($bounds:tt)* })+) => {
+        $(
+            impl<$($generics)*> Debounce<T> for $debouncer
+            where
+                $($bounds)*
+            {
+                type Value = $value;
+                fn update(&mut self, new_value: T) -> State<T, $value> {
+                    self.update(new_value)
+                }
+                fn stable(&self) -> <$value as Value>::V {
+                    self.read_stable()
+                }
+            }
+        )+
+    };
+}
+impl_debounce! {
+    [M, T, V, O] TimedDebouncer<M, T, V, O> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+        O: TransitionObserver<M, T, V>,
+    }
+    [M, T, V] CachedDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] Cascade<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] HybridDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] MinHoldDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] StickyDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] ValidatedDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, const K: usize, V] TableDebouncer<M, T, K, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, const TICKS: u64, V] FixedDebouncer<M, T, TICKS, V> => V {
+        M: Monotonic,
+        M::Duration: TickDuration + Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] AdaptiveDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy + PhysicalDuration,
+        M::Instant: Sub<Output = M::Duration>,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] BackoffDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy + PhysicalDuration,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T] PulseWidthDebouncer<M, T> => InitializedValue<T> {
+        M: Monotonic,
+        M::Duration: Copy,
+        M::Instant: Sub<Output = M::Duration>,
+        T: PartialEq + Copy,
+    }
+    [M, T, V] JumpDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + PartialOrd + Sub<Output = T> + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, const K: usize, V] HistoryDebouncer<M, T, K, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] RangeDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialOrd + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] UptimeDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] StuckDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] StatsDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] LatchingDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+    [M, T, V] WarmupDebouncer<M, T, V> => V {
+        M: Monotonic,
+        M::Duration: Copy,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    fn settle(debouncer: &mut dyn Debounce<bool, Value = InitializedValue<bool>>) -> bool {
+        debouncer.update(true);
+        MockMonotonic::add(11.millis());
+        debouncer.update(true).transitioned()
+    }
+
+    fn settle_to(
+        debouncer: &mut dyn Debounce<u8, Value = InitializedValue<u8>>,
+        value: u8,
+    ) -> bool {
+        debouncer.update(value);
+        MockMonotonic::add(11.millis());
+        debouncer.update(value).transitioned_to(&value)
+    }
+
+    #[test]
+    fn test_dyn_numeric_strategies() {
+        run_test(|_| {
+            let mut jump = JumpDebouncer::<MockMonotonic, u8>::new(0, 10.millis(), 2.millis(), 50);
+            let mut history = HistoryDebouncer::<MockMonotonic, u8, 4>::new(0, 10.millis());
+            let mut range =
+                RangeDebouncer::<MockMonotonic, u8>::new_with_range(0, 10.millis(), 0, 100);
+            let strategies: [&mut dyn Debounce<u8, Value = InitializedValue<u8>>; 3] =
+                [&mut jump, &mut history, &mut range];
+            for debouncer in strategies {
+                assert!(settle_to(debouncer, 7));
+                assert_eq!(debouncer.stable(), 7);
+            }
+        });
+    }
+
+    #[test]
+    fn test_dyn_strategies() {
+        run_test(|_| {
+            let mut timed = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            let mut hybrid = HybridDebouncer::<MockMonotonic, _>::new(false, 10.millis(), 2);
+            let strategies: [&mut dyn Debounce<bool, Value = InitializedValue<bool>>; 2] =
+                [&mut timed, &mut hybrid];
+            for debouncer in strategies {
+                assert!(settle(debouncer));
+                assert!(debouncer.stable());
+            }
+        });
+    }
+}
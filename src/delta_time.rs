@@ -0,0 +1,92 @@
+use crate::{
+    DebounceState, InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value,
+};
+
+/// Debouncer fed with the time elapsed since the last update instead of reading
+/// [`Monotonic::now`].
+///
+/// This suits systems that only know the time since the last poll, e.g. from a timer capture. The
+/// debouncer keeps its own clock, which starts at [`Monotonic::ZERO`] and advances by the elapsed
+/// time of every update, and then decides like [`TimedDebouncer::update_at`].
+pub struct DeltaTimeDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    clock: M::Instant,
+}
+
+impl<M, T> DeltaTimeDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value.
+    pub fn new(initial_value: T, debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            clock: M::ZERO,
+        }
+    }
+}
+impl<M, T> DeltaTimeDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state.
+    pub fn new_unknown(debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::from_state(
+                DebounceState::new_unknown(M::ZERO),
+                debounce_time,
+            ),
+            clock: M::ZERO,
+        }
+    }
+}
+impl<M, T, V> DeltaTimeDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Advances the clock of the debouncer by `elapsed`, then updates it with a new value and
+    /// returns the current state.
+    pub fn update(&mut self, new_value: T, elapsed: M::Duration) -> State<T, V> {
+        self.clock = self.clock + elapsed;
+        self.debouncer.update_at(new_value, self.clock)
+    }
+    /// Returns the time of the internal clock, i.e. the sum of all elapsed times.
+    pub fn clock(&self) -> M::Instant {
+        self.clock
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockMonotonic;
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_matches_absolute_time() {
+        let samples = [true, false, true, true, true, true, false, true];
+        let mut absolute = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+        let mut relative = DeltaTimeDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+        for (i, sample) in samples.into_iter().enumerate() {
+            let now = fugit::TimerInstantU64::<1_000_000>::from_ticks(4_000 * (i as u64 + 1));
+            assert_eq!(
+                absolute.update_at(sample, now),
+                relative.update(sample, 4.millis())
+            );
+        }
+        assert!(relative.read_stable());
+        assert_eq!(relative.clock().ticks(), 32_000);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::Input;
+
+/// Input adapter for a switch with two complementary contacts, as commonly used for safety
+/// inputs.
+///
+/// In a valid state the two pins have opposite levels and [`read`](Input::read) returns
+/// `Some(level)` with the level of the `high` pin. Equal levels indicate a broken contact or
+/// wiring fault and read as `None`. This is meant to be fed to a
+/// [`SignalLossDebouncer`](crate::SignalLossDebouncer), so a persisting fault reverts the
+/// debounced value to unknown.
+pub struct Differential<H, L> {
+    high: H,
+    low: L,
+}
+
+impl<H: Input<bool>, L: Input<bool>> Differential<H, L> {
+    /// Creates a new [`Differential`] from the normally open contact `high` and the normally
+    /// closed contact `low`.
+    pub fn new(high: H, low: L) -> Self {
+        Self { high, low }
+    }
+    /// Returns the wrapped inputs.
+    pub fn into_inner(self) -> (H, L) {
+        (self.high, self.low)
+    }
+}
+
+impl<H: Input<bool>, L: Input<bool>> Input<Option<bool>> for Differential<H, L> {
+    fn read(&mut self) -> Option<bool> {
+        let high = self.high.read();
+        let low = self.low.read();
+        (high != low).then_some(high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use crate::{SignalLossDebouncer, State};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_pairings() {
+        let high = AtomicBool::new(true);
+        let low = AtomicBool::new(false);
+        let mut input = Differential::new(&high, &low);
+        assert_eq!(input.read(), Some(true));
+        high.store(false, Ordering::Relaxed);
+        low.store(true, Ordering::Relaxed);
+        assert_eq!(input.read(), Some(false));
+        high.store(true, Ordering::Relaxed);
+        assert_eq!(input.read(), None);
+        high.store(false, Ordering::Relaxed);
+        low.store(false, Ordering::Relaxed);
+        assert_eq!(input.read(), None);
+    }
+
+    #[test]
+    fn test_fault_reverts_to_unknown() {
+        static HIGH: AtomicBool = AtomicBool::new(true);
+        static LOW: AtomicBool = AtomicBool::new(false);
+        run_test(|_| {
+            let mut input = Differential::new(&HIGH, &LOW);
+            let mut debouncer =
+                SignalLossDebouncer::<MockMonotonic, bool>::new(10.millis(), 10.millis());
+            debouncer.update(input.read());
+            MockMonotonic::add(11.millis());
+            assert_eq!(
+                debouncer.update(input.read()),
+                State::Initialized { value: true }
+            );
+
+            LOW.store(true, Ordering::Relaxed);
+            assert_eq!(debouncer.update(input.read()).stable(), Some(true));
+            MockMonotonic::add(11.millis());
+            assert_eq!(debouncer.update(input.read()).stable(), None);
+        });
+    }
+}
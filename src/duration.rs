@@ -0,0 +1,209 @@
+use core::ops::Add;
+
+use crate::Monotonic;
+
+/// A [`Monotonic::Duration`] that is guaranteed to be greater than zero.
+///
+/// The core [`TimedDebouncer`](crate::TimedDebouncer) and the debouncers built on it accept a zero
+/// debounce time, in which case every new value is passed through immediately. Durations for which
+/// zero is a logic error take a `NonZeroDuration` instead, so a misconfiguration is caught at
+/// construction rather than silently misbehaving. These are:
+///
+/// - the hold time of [`MinHoldDebouncer`](crate::MinHoldDebouncer), which would not hold at all,
+/// - the minimum window, burst interval and decay of [`BackoffDebouncer`](crate::BackoffDebouncer),
+///   which would disable the back-off.
+pub struct NonZeroDuration<M: Monotonic>(M::Duration);
+
+impl<M: Monotonic> NonZeroDuration<M>
+where
+    M::Duration: Copy,
+{
+    /// Creates a new `NonZeroDuration` if the given duration is greater than zero.
+    pub fn new(duration: M::Duration) -> Option<Self> {
+        if M::ZERO + duration > M::ZERO {
+            Some(Self(duration))
+        } else {
+            None
+        }
+    }
+    /// Returns the contained duration.
+    pub fn get(self) -> M::Duration {
+        self.0
+    }
+}
+
+impl<M: Monotonic> Clone for NonZeroDuration<M>
+where
+    M::Duration: Copy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<M: Monotonic> Copy for NonZeroDuration<M> where M::Duration: Copy {}
+
+impl<M: Monotonic> core::fmt::Debug for NonZeroDuration<M>
+where
+    M::Duration: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("NonZeroDuration").field(&self.0).finish()
+    }
+}
+
+/// Conversion of a [`Monotonic::Duration`] from and to physical time.
+///
+/// This allows specifying debounce times independently of the tick rate of the underlying timer.
+/// If the feature `fugit` is enabled this is implemented for all `fugit` durations.
+pub trait PhysicalDuration: Sized {
+    /// Creates a duration from a number of microseconds, rounded down to whole ticks.
+    fn from_micros(micros: u64) -> Self;
+    /// Returns the duration as a number of microseconds, rounded down.
+    fn as_micros(&self) -> u64;
+    /// Creates a duration from a number of milliseconds, rounded down to whole ticks.
+    fn from_millis(millis: u64) -> Self {
+        Self::from_micros(millis.saturating_mul(1_000))
+    }
+    /// Returns the duration as a number of milliseconds, rounded down.
+    fn as_millis(&self) -> u64 {
+        self.as_micros() / 1_000
+    }
+}
+
+/// Converts a duration of the monotonic `A` into a duration of the monotonic `B`, with microsecond precision.
+pub fn convert_duration<A: Monotonic, B: Monotonic>(duration: A::Duration) -> B::Duration
+where
+    A::Duration: PhysicalDuration,
+    B::Duration: PhysicalDuration,
+{
+    B::Duration::from_micros(duration.as_micros())
+}
+
+/// Returns the worst-case time from a change of the input until the debouncer reports it, if the
+/// input is polled every `poll_interval`.
+///
+/// The change can happen right after a poll, so it is seen up to one `poll_interval` late, and
+/// then has to stay for the `debounce_time`. Since the debounce time is only checked on polls, it
+/// can run out right after one and is only reported on the next, up to another `poll_interval`
+/// later.
+pub fn worst_case_latency<D: Add<Output = D> + Copy>(debounce_time: D, poll_interval: D) -> D {
+    debounce_time + poll_interval + poll_interval
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "fugit")))]
+#[cfg(feature = "fugit")]
+impl<const NOM: u32, const DENOM: u32> PhysicalDuration for fugit::Duration<u32, NOM, DENOM> {
+    fn from_micros(micros: u64) -> Self {
+        let ticks = fugit::Duration::<u64, NOM, DENOM>::micros(micros).ticks();
+        Self::from_ticks(ticks.min(u32::MAX as u64) as u32)
+    }
+    fn as_micros(&self) -> u64 {
+        fugit::Duration::<u64, NOM, DENOM>::from_ticks(self.ticks() as u64).to_micros()
+    }
+}
+#[cfg_attr(docsrs, doc(cfg(feature = "fugit")))]
+#[cfg(feature = "fugit")]
+impl<const NOM: u32, const DENOM: u32> PhysicalDuration for fugit::Duration<u64, NOM, DENOM> {
+    fn from_micros(micros: u64) -> Self {
+        Self::micros(micros)
+    }
+    fn as_micros(&self) -> u64 {
+        self.to_micros()
+    }
+}
+
+/// Conversion of a [`Monotonic::Duration`] from and to a raw number of timer ticks.
+///
+/// If the feature `fugit` is enabled this is implemented for all `fugit` durations.
+pub trait TickDuration: Sized {
+    /// Creates a duration from a number of ticks, saturating at the largest representable duration.
+    fn from_ticks(ticks: u64) -> Self;
+    /// Returns the duration as a number of ticks.
+    fn ticks(&self) -> u64;
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "fugit")))]
+#[cfg(feature = "fugit")]
+impl<const NOM: u32, const DENOM: u32> TickDuration for fugit::Duration<u32, NOM, DENOM> {
+    fn from_ticks(ticks: u64) -> Self {
+        Self::from_ticks(ticks.min(u32::MAX as u64) as u32)
+    }
+    fn ticks(&self) -> u64 {
+        self.ticks() as u64
+    }
+}
+#[cfg_attr(docsrs, doc(cfg(feature = "fugit")))]
+#[cfg(feature = "fugit")]
+impl<const NOM: u32, const DENOM: u32> TickDuration for fugit::Duration<u64, NOM, DENOM> {
+    fn from_ticks(ticks: u64) -> Self {
+        Self::from_ticks(ticks)
+    }
+    fn ticks(&self) -> u64 {
+        self.ticks()
+    }
+}
+
+#[cfg(all(test, feature = "fugit"))]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use crate::TimedDebouncer;
+    use fugit::ExtU64;
+
+    struct RtcMonotonic;
+    impl Monotonic for RtcMonotonic {
+        type Instant = fugit::TimerInstantU32<32_768>;
+        type Duration = fugit::TimerDurationU32<32_768>;
+        const ZERO: Self::Instant = Self::Instant::from_ticks(0);
+        fn now() -> Self::Instant {
+            Self::ZERO
+        }
+    }
+
+    #[test]
+    fn test_convert_duration() {
+        let rtc = convert_duration::<MockMonotonic, RtcMonotonic>(10.millis());
+        assert_eq!(rtc.ticks(), 327);
+        let timer = convert_duration::<RtcMonotonic, MockMonotonic>(rtc);
+        assert_eq!(timer.ticks(), 9_979);
+        assert_eq!(
+            <RtcMonotonic as Monotonic>::Duration::from_millis(1_000).ticks(),
+            32_768
+        );
+    }
+
+    type MockDuration = <MockMonotonic as Monotonic>::Duration;
+
+    fn measure_latency(debounce_time: MockDuration, poll_interval: MockDuration) -> MockDuration {
+        let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, debounce_time);
+        debouncer.update(false);
+        // the input changes right after a poll
+        let changed_at = MockMonotonic::now() + 1.micros();
+        while !debouncer
+            .update(changed_at <= MockMonotonic::now())
+            .transitioned()
+        {
+            MockMonotonic::add(poll_interval);
+        }
+        MockMonotonic::now() - changed_at
+    }
+
+    #[test]
+    fn test_worst_case_latency() {
+        run_test(|_| {
+            let latency: MockDuration = worst_case_latency(10.millis(), 2.millis());
+            assert_eq!(latency.ticks(), 14_000);
+            assert!(measure_latency(10.millis(), 2.millis()) <= latency);
+        });
+    }
+
+    #[test]
+    fn test_worst_case_latency_uneven_poll_interval() {
+        run_test(|_| {
+            let latency: MockDuration = worst_case_latency(10.millis(), 3.millis());
+            let measured = measure_latency(10.millis(), 3.millis());
+            assert!(measured.ticks() > 13_000);
+            assert!(measured <= latency);
+        });
+    }
+}
@@ -0,0 +1,180 @@
+use core::marker::PhantomData;
+
+use crate::{Input, Monotonic, State, TimedDebouncer, UninitializedValue};
+
+/// Determines how a [`FallibleDebouncedInput`] reacts to a failed read.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Keep the current state and return the error.
+    #[default]
+    Keep,
+    /// Reset the debouncer to the unknown state, like
+    /// [`TimedDebouncer::cold_reset`](crate::TimedDebouncer::cold_reset), and return the error.
+    /// The configuration of the debouncer is kept.
+    ResetToUnknown,
+    /// Skip the failed sample, count the error and return the current state.
+    Count,
+}
+
+/// Debouncing wrapper for inputs whose reads may fail.
+///
+/// Unlike a [`DebouncedInput`](crate::DebouncedInput) over a `Result`, which debounces the
+/// `Result` itself, this only debounces successfully read values and handles errors according to
+/// its [`ErrorPolicy`]. The state is unknown until the first value became stable.
+pub struct FallibleDebouncedInput<M: Monotonic, T: Copy, E, I> {
+    debouncer: TimedDebouncer<M, T, UninitializedValue<T>>,
+    input: I,
+    policy: ErrorPolicy,
+    errors: u32,
+    _error: PhantomData<E>,
+}
+
+impl<M, T, E, I> FallibleDebouncedInput<M, T, E, I>
+where
+    I: Input<Result<T, E>>,
+    M: Monotonic,
+    M::Duration: Copy,
+    T: Copy + PartialEq,
+{
+    /// Creates a new [`FallibleDebouncedInput`] by wrapping an [`Input`]
+    pub fn new(input: I, debounce_time: M::Duration, policy: ErrorPolicy) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            input,
+            policy,
+            errors: 0,
+            _error: PhantomData,
+        }
+    }
+    /// Read the current state of the input.
+    ///
+    /// Returns the error of a failed read unless the policy is [`ErrorPolicy::Count`].
+    pub fn read(&mut self) -> Result<State<T, UninitializedValue<T>>, E> {
+        match self.input.read() {
+            Ok(value) => Ok(self.debouncer.update(value)),
+            Err(error) => match self.policy {
+                ErrorPolicy::Keep => Err(error),
+                ErrorPolicy::ResetToUnknown => {
+                    self.debouncer.cold_reset();
+                    Err(error)
+                }
+                ErrorPolicy::Count => {
+                    self.errors = self.errors.saturating_add(1);
+                    Ok(self.debouncer.read())
+                }
+            },
+        }
+    }
+}
+
+impl<M, T, E, I> FallibleDebouncedInput<M, T, E, I>
+where
+    M: Monotonic,
+    T: Copy,
+{
+    /// Read the last stable state of the input, or `None` if it is unknown.
+    pub fn read_stable(&self) -> Option<T> {
+        self.debouncer.read_stable()
+    }
+    /// Returns the [`ErrorPolicy`].
+    pub fn policy(&self) -> ErrorPolicy {
+        self.policy
+    }
+    /// Changes the [`ErrorPolicy`].
+    pub fn set_policy(&mut self, policy: ErrorPolicy) {
+        self.policy = policy;
+    }
+    /// Returns the number of failed reads counted with [`ErrorPolicy::Count`].
+    pub fn error_count(&self) -> u32 {
+        self.errors
+    }
+    /// Returns the underlying [`TimedDebouncer`].
+    pub fn debouncer(&self) -> &TimedDebouncer<M, T, UninitializedValue<T>> {
+        &self.debouncer
+    }
+    /// Returns the underlying [`TimedDebouncer`] mutably, e.g. to configure it.
+    pub fn debouncer_mut(&mut self) -> &mut TimedDebouncer<M, T, UninitializedValue<T>> {
+        &mut self.debouncer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    /// Input that fails on every third read.
+    struct Flaky(u32);
+    impl Input<Result<bool, ()>> for Flaky {
+        fn read(&mut self) -> Result<bool, ()> {
+            self.0 += 1;
+            if self.0.is_multiple_of(3) {
+                Err(())
+            } else {
+                Ok(true)
+            }
+        }
+    }
+
+    fn settle(input: &mut FallibleDebouncedInput<MockMonotonic, bool, (), Flaky>) {
+        for _ in 0..2 {
+            let _ = input.read();
+            MockMonotonic::add(11.millis());
+        }
+        assert_eq!(input.read_stable(), Some(true));
+    }
+
+    #[test]
+    fn test_keep() {
+        run_test(|_| {
+            let mut input = FallibleDebouncedInput::new(Flaky(0), 10.millis(), ErrorPolicy::Keep);
+            settle(&mut input);
+            assert_eq!(input.read(), Err(()));
+            assert_eq!(input.read_stable(), Some(true));
+        });
+    }
+
+    #[test]
+    fn test_reset_to_unknown() {
+        run_test(|_| {
+            let mut input =
+                FallibleDebouncedInput::new(Flaky(0), 10.millis(), ErrorPolicy::ResetToUnknown);
+            settle(&mut input);
+            assert_eq!(input.read(), Err(()));
+            assert_eq!(input.read_stable(), None);
+        });
+    }
+
+    #[test]
+    fn test_reset_to_unknown_keeps_configuration() {
+        run_test(|_| {
+            let mut input =
+                FallibleDebouncedInput::new(Flaky(0), 10.millis(), ErrorPolicy::ResetToUnknown);
+            let debouncer = input.debouncer_mut();
+            debouncer.set_max_unstable_time(Some(50.millis()));
+            debouncer.set_debounce_return_to_stable(true);
+            settle(&mut input);
+            assert_eq!(input.read(), Err(()));
+            assert_eq!(input.read_stable(), None);
+            let debouncer = input.debouncer();
+            assert_eq!(debouncer.debounce_time().ticks(), 10_000);
+            assert_eq!(
+                debouncer.max_unstable_time().map(|t| t.ticks()),
+                Some(50_000)
+            );
+            assert!(debouncer.debounce_return_to_stable());
+        });
+    }
+
+    #[test]
+    fn test_count() {
+        run_test(|_| {
+            let mut input = FallibleDebouncedInput::new(Flaky(0), 10.millis(), ErrorPolicy::Count);
+            settle(&mut input);
+            assert_eq!(input.read(), Ok(State::Stable { value: true }));
+            assert_eq!(input.error_count(), 1);
+            assert_eq!(input.read_stable(), Some(true));
+        });
+    }
+}
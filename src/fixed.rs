@@ -0,0 +1,93 @@
+use crate::{
+    step, DebounceState, InitializedValue, Monotonic, State, TickDuration, TimedDebouncer,
+    UninitializedValue, Value,
+};
+
+/// Debouncer with a debounce time of `TICKS` ticks of the monotonic fixed at compile time.
+///
+/// Compared to [`TimedDebouncer`] this does not store the debounce time, which makes it smaller
+/// and lets the compiler specialize the comparison. It does not support observers or a maximum
+/// unstable time; convert it into a [`TimedDebouncer`] if those are needed.
+pub struct FixedDebouncer<M: Monotonic, T, const TICKS: u64, V: Value<T = T> = InitializedValue<T>>
+{
+    state: DebounceState<V, M::Instant>,
+}
+
+impl<M: Monotonic, T: Copy, const TICKS: u64> FixedDebouncer<M, T, TICKS, InitializedValue<T>> {
+    /// Creates a new Debouncer with a known initial value.
+    pub fn new(initial_value: T) -> Self {
+        Self {
+            state: DebounceState::new(initial_value, M::ZERO),
+        }
+    }
+}
+impl<M: Monotonic, T: Copy, const TICKS: u64> FixedDebouncer<M, T, TICKS, UninitializedValue<T>> {
+    /// Creates a new Debouncer that starts with an unkown state.
+    pub fn new_unknown() -> Self {
+        Self {
+            state: DebounceState::new_unknown(M::now()),
+        }
+    }
+}
+impl<M, T, const TICKS: u64, V> FixedDebouncer<M, T, TICKS, V>
+where
+    M: Monotonic,
+    M::Duration: TickDuration + Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Returns the debounce time.
+    pub fn debounce_time() -> M::Duration {
+        M::Duration::from_ticks(TICKS)
+    }
+    /// Updates the debouncer state with a new value and returns the current state.
+    ///
+    /// This runs the same algorithm as [`TimedDebouncer::update`] with the default settings.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        step::<M, T, V>(&mut self.state, Self::debounce_time(), new_value, M::now())
+    }
+    /// Converts this debouncer into a [`TimedDebouncer`] with the same state and debounce time.
+    pub fn into_timed(self) -> TimedDebouncer<M, T, V> {
+        TimedDebouncer::from_state(self.state, Self::debounce_time())
+    }
+    /// Creates a debouncer with the state of a [`TimedDebouncer`]. Its settings are discarded.
+    pub fn from_timed<O>(debouncer: TimedDebouncer<M, T, V, O>) -> Self {
+        Self {
+            state: debouncer.debounce_state(),
+        }
+    }
+}
+impl<M, T, const TICKS: u64, V> FixedDebouncer<M, T, TICKS, V>
+where
+    M: Monotonic,
+    V: Value<T = T>,
+    V::V: Copy,
+{
+    /// Reads the current stable value, if available. This does not update the internal state and just returns the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        *self.state.stable
+    }
+}
+
+#[cfg(all(test, feature = "fugit"))]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_fixed_matches_timed() {
+        run_test(|_| {
+            let mut fixed = FixedDebouncer::<MockMonotonic, _, 10_000>::new(false);
+            let mut timed = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            for (value, step) in [(true, 3), (false, 2), (true, 5), (true, 6), (true, 1)] {
+                assert_eq!(fixed.update(value), timed.update(value));
+                MockMonotonic::add(step.millis());
+            }
+            let mut timed = fixed.into_timed();
+            assert_eq!(timed.debounce_time().ticks(), 10_000);
+            assert_eq!(timed.read(), State::Stable { value: true });
+        });
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Debouncer that records the distinct candidate values seen while the input was settling.
+///
+/// The history holds up to `K` values in the order they first appeared; a value that is already in
+/// the history is not recorded again. If more distinct values appear in a single window, the
+/// oldest ones are dropped, after which a dropped value can be recorded again. The history is kept after a transition, so it
+/// can be read together with the new stable value, and is cleared when the next window opens.
+pub struct HistoryDebouncer<M: Monotonic, T, const K: usize, V: Value<T = T> = InitializedValue<T>>
+{
+    debouncer: TimedDebouncer<M, T, V>,
+    history: Option<[T; K]>,
+    len: usize,
+    window_closed: bool,
+}
+
+impl<M, T, const K: usize> HistoryDebouncer<M, T, K, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value.
+    pub fn new(initial_value: T, debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            history: None,
+            len: 0,
+            window_closed: true,
+        }
+    }
+}
+impl<M, T, const K: usize> HistoryDebouncer<M, T, K, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state.
+    pub fn new_unknown(debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            history: None,
+            len: 0,
+            window_closed: true,
+        }
+    }
+}
+impl<M, T, const K: usize, V> HistoryDebouncer<M, T, K, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        let state = self.debouncer.update(new_value);
+        if !matches!(state, State::Stable { .. }) {
+            if self.window_closed {
+                self.len = 0;
+            }
+            self.record(new_value);
+        }
+        self.window_closed = !matches!(state, State::Unstable { .. });
+        state
+    }
+    fn record(&mut self, value: T) {
+        if K == 0 || self.candidate_history().contains(&value) {
+            return;
+        }
+        let history = self.history.get_or_insert([value; K]);
+        if self.len == K {
+            history.rotate_left(1);
+            self.len -= 1;
+        }
+        history[self.len] = value;
+        self.len += 1;
+    }
+    /// Returns the distinct candidate values of the current or most recent window, oldest first.
+    pub fn candidate_history(&self) -> &[T] {
+        match &self.history {
+            Some(history) => &history[..self.len],
+            None => &[],
+        }
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_candidate_history() {
+        run_test(|_| {
+            let mut debouncer = HistoryDebouncer::<MockMonotonic, u8, 3>::new(0, 10.millis());
+            for value in [1, 1, 2, 3, 4] {
+                debouncer.update(value);
+                MockMonotonic::add(1.millis());
+            }
+            MockMonotonic::add(10.millis());
+            assert!(debouncer.update(4).transitioned());
+            // the oldest candidate was dropped
+            assert_eq!(debouncer.candidate_history(), &[2, 3, 4]);
+            debouncer.update(4);
+            assert_eq!(debouncer.candidate_history(), &[2, 3, 4]);
+            debouncer.update(5);
+            assert_eq!(debouncer.candidate_history(), &[5]);
+        });
+    }
+
+    #[test]
+    fn test_repeated_candidates_are_recorded_once() {
+        run_test(|_| {
+            let mut debouncer = HistoryDebouncer::<MockMonotonic, u8, 3>::new(0, 10.millis());
+            assert!(debouncer.candidate_history().is_empty());
+            for value in [1, 2, 1, 2, 1] {
+                debouncer.update(value);
+                MockMonotonic::add(1.millis());
+            }
+            assert_eq!(debouncer.candidate_history(), &[1, 2]);
+        });
+    }
+}
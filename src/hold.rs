@@ -0,0 +1,148 @@
+use crate::{
+    InitializedValue, Monotonic, NonZeroDuration, State, TimedDebouncer, UninitializedValue, Value,
+};
+
+/// Debouncer that keeps every newly adopted stable value for a minimum hold time.
+///
+/// Transitions that would happen before the hold time after the previous transition lapsed are
+/// deferred. The hold time is measured from the instant a value became stable, not from the poll
+/// that detected it. The pending value is re-evaluated on the first update after the hold time, so it is
+/// adopted if it is still present and stable by then. This limits how fast the output can cycle,
+/// which is useful for displays or actuators, even if the changes of the input are legitimate.
+///
+/// The hold time is a [`NonZeroDuration`], since a zero hold time would not limit anything.
+pub struct MinHoldDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    min_hold_time: M::Duration,
+    held_since: Option<M::Instant>,
+}
+
+impl<M, T> MinHoldDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value. The initial value is not subject to
+    /// the hold time.
+    pub fn new(
+        initial_value: T,
+        debounce_time: M::Duration,
+        min_hold_time: NonZeroDuration<M>,
+    ) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            min_hold_time: min_hold_time.get(),
+            held_since: None,
+        }
+    }
+}
+impl<M, T> MinHoldDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state.
+    pub fn new_unknown(debounce_time: M::Duration, min_hold_time: NonZeroDuration<M>) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            min_hold_time: min_hold_time.get(),
+            held_since: None,
+        }
+    }
+}
+impl<M: Monotonic, T, V: Value<T = T>> MinHoldDebouncer<M, T, V>
+where
+    M::Duration: Copy,
+{
+    /// Get the minimum hold time.
+    pub fn min_hold_time(&self) -> M::Duration {
+        self.min_hold_time
+    }
+    /// Changes the minimum hold time.
+    pub fn set_min_hold_time(&mut self, min_hold_time: NonZeroDuration<M>) {
+        self.min_hold_time = min_hold_time.get();
+    }
+}
+impl<M, T, V> MinHoldDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        let now = M::now();
+        let held = self
+            .held_since
+            .is_none_or(|since| now >= since + self.min_hold_time);
+        let state = self.debouncer.update_outcome(new_value, held, now).0;
+        if state.transitioned() {
+            // the value was adopted when it settled, but not before the previous hold time lapsed
+            let settled_at = self.debouncer.settled_at(now);
+            self.held_since = Some(match self.held_since {
+                Some(since) => settled_at.max(since + self.min_hold_time),
+                None => settled_at,
+            });
+        }
+        state
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_min_hold() {
+        run_test(|_| {
+            let min_hold_time = NonZeroDuration::new(50.millis()).unwrap();
+            let mut debouncer =
+                MinHoldDebouncer::<MockMonotonic, u8>::new(0, 5.millis(), min_hold_time);
+            let mut adopted = [(0, 0); 2];
+            let mut count = 0;
+            let mut value = 0;
+            for t in 0..100 {
+                // a new valid value every 10 ms
+                if t % 10 == 0 {
+                    value += 1;
+                }
+                if debouncer.update(value).transitioned() {
+                    adopted[count] = (t, value);
+                    count += 1;
+                }
+                MockMonotonic::add(1.millis());
+            }
+            // the second transition is deferred until the hold time lapsed
+            assert_eq!(count, 2);
+            assert_eq!(adopted, [(5, 1), (55, 6)]);
+        });
+    }
+
+    #[test]
+    fn test_hold_starts_at_transition_instant() {
+        run_test(|_| {
+            let min_hold_time = NonZeroDuration::new(50.millis()).unwrap();
+            let mut debouncer =
+                MinHoldDebouncer::<MockMonotonic, u8>::new(0, 5.millis(), min_hold_time);
+            debouncer.update(1);
+            // the value settles after 5 ms but is only polled after 20 ms
+            MockMonotonic::add(20.millis());
+            assert!(debouncer.update(1).transitioned());
+            debouncer.update(2);
+            MockMonotonic::add(34.millis());
+            assert!(!debouncer.update(2).transitioned());
+            MockMonotonic::add(1.millis());
+            assert!(debouncer.update(2).transitioned());
+        });
+    }
+}
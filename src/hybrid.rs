@@ -0,0 +1,178 @@
+use core::ops::Sub;
+
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Debouncer that requires both a minimum number of consecutive identical samples and a minimum
+/// time before accepting a new value.
+///
+/// This works well across varying polling rates: with slow polling the sample count dominates,
+/// with fast polling the debounce time does.
+///
+/// Code migrating from a fixed-interval poller that counts "N good polls" can set `min_samples`
+/// to the required poll count and use `debounce_time` as a floor for when polling runs faster
+/// than expected. [`HybridDebouncer::consecutive_samples`] and [`HybridDebouncer::elapsed_time`]
+/// report the progress towards both requirements.
+pub struct HybridDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    min_samples: u32,
+    last_sample: Option<T>,
+    samples: u32,
+}
+
+impl<M, T> HybridDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value.
+    pub fn new(initial_value: T, debounce_time: M::Duration, min_samples: u32) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            min_samples,
+            last_sample: None,
+            samples: 0,
+        }
+    }
+}
+impl<M, T> HybridDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state.
+    pub fn new_unknown(debounce_time: M::Duration, min_samples: u32) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            min_samples,
+            last_sample: None,
+            samples: 0,
+        }
+    }
+}
+impl<M: Monotonic, T, V: Value<T = T>> HybridDebouncer<M, T, V> {
+    /// Get the debounce time.
+    pub fn debounce_time(&self) -> M::Duration
+    where
+        M::Duration: Copy,
+    {
+        self.debouncer.debounce_time()
+    }
+    /// Changes the debounce time.
+    pub fn set_debounce_time(&mut self, debounce_time: M::Duration) {
+        self.debouncer.set_debounce_time(debounce_time);
+    }
+    /// Get the number of consecutive identical samples required for a transition.
+    pub fn min_samples(&self) -> u32 {
+        self.min_samples
+    }
+    /// Changes the number of consecutive identical samples required for a transition.
+    pub fn set_min_samples(&mut self, min_samples: u32) {
+        self.min_samples = min_samples;
+    }
+    /// Returns the number of consecutive identical samples of the most recent value.
+    pub fn consecutive_samples(&self) -> u32 {
+        self.samples
+    }
+    /// Returns the time since the most recent value changed, i.e. the time counted towards the
+    /// debounce time.
+    pub fn elapsed_time(&self) -> M::Duration
+    where
+        M::Instant: Sub<Output = M::Duration>,
+    {
+        M::now() - self.debouncer.last_change_time()
+    }
+}
+impl<M, T, V> HybridDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        if self.last_sample == Some(new_value) {
+            self.samples = self.samples.saturating_add(1);
+        } else {
+            self.last_sample = Some(new_value);
+            self.samples = 1;
+        }
+        self.debouncer
+            .update_gated(new_value, self.samples >= self.min_samples)
+    }
+}
+impl<M, T, V> HybridDebouncer<M, T, V>
+where
+    M: Monotonic,
+    V: Value<T = T>,
+    V::V: Copy,
+{
+    /// Reads the current stable value, if available. This does not update the internal state and just returns the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_time_met_count_not() {
+        run_test(|_| {
+            let mut debouncer = HybridDebouncer::<MockMonotonic, _>::new(false, 10.millis(), 4);
+            debouncer.update(true);
+            MockMonotonic::add(20.millis());
+            assert!(!debouncer.update(true).transitioned());
+            assert!(!debouncer.update(true).transitioned());
+            assert!(debouncer.update(true).transitioned());
+        });
+    }
+
+    #[test]
+    fn test_count_met_time_not() {
+        run_test(|_| {
+            let mut debouncer = HybridDebouncer::<MockMonotonic, _>::new(false, 10.millis(), 2);
+            for _ in 0..5 {
+                assert!(!debouncer.update(true).transitioned());
+                MockMonotonic::add(1.millis());
+            }
+            MockMonotonic::add(5.millis());
+            assert!(debouncer.update(true).transitioned());
+        });
+    }
+
+    #[test]
+    fn test_cycles_fast_polling() {
+        run_test(|_| {
+            let mut debouncer = HybridDebouncer::<MockMonotonic, _>::new(false, 10.millis(), 3);
+            for polls in 1..=9 {
+                assert!(!debouncer.update(true).transitioned());
+                assert_eq!(debouncer.consecutive_samples(), polls);
+                MockMonotonic::add(1.millis());
+            }
+            assert_eq!(debouncer.elapsed_time().ticks(), 9_000);
+            MockMonotonic::add(1.millis());
+            assert!(debouncer.update(true).transitioned());
+        });
+    }
+
+    #[test]
+    fn test_cycles_slow_polling() {
+        run_test(|_| {
+            let mut debouncer = HybridDebouncer::<MockMonotonic, _>::new(false, 10.millis(), 3);
+            assert!(!debouncer.update(true).transitioned());
+            MockMonotonic::add(50.millis());
+            assert!(!debouncer.update(true).transitioned());
+            assert_eq!(debouncer.elapsed_time().ticks(), 50_000);
+            MockMonotonic::add(50.millis());
+            assert!(debouncer.update(true).transitioned());
+            assert_eq!(debouncer.consecutive_samples(), 3);
+        });
+    }
+}
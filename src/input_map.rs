@@ -0,0 +1,89 @@
+use crate::{Edge, Monotonic, TimedDebouncer};
+
+/// Debounces `N` named boolean inputs and reports edges by name.
+///
+/// The keys are usually variants of an enum naming the inputs, e.g. the buttons of a keypad. Use
+/// [`input_map!`](crate::input_map!) to declare a map concisely.
+pub struct InputMap<M: Monotonic, K, const N: usize> {
+    keys: [K; N],
+    debouncers: [TimedDebouncer<M, bool>; N],
+}
+
+impl<M, K, const N: usize> InputMap<M, K, N>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    K: Copy + PartialEq,
+{
+    /// Creates a new [`InputMap`] with all inputs initially `false`.
+    pub fn new(keys: [K; N], debounce_time: M::Duration) -> Self {
+        Self {
+            keys,
+            debouncers: core::array::from_fn(|_| TimedDebouncer::new(false, debounce_time)),
+        }
+    }
+    /// Updates all inputs with raw samples, given in the same order as the keys, and returns the
+    /// keys of all inputs that transitioned together with their edge.
+    pub fn update(&mut self, raw_samples: [bool; N]) -> impl Iterator<Item = (K, Edge)> {
+        let mut edges = [None; N];
+        for ((debouncer, sample), edge) in self
+            .debouncers
+            .iter_mut()
+            .zip(raw_samples)
+            .zip(edges.iter_mut())
+        {
+            *edge = debouncer.update_edge(sample);
+        }
+        self.keys
+            .into_iter()
+            .zip(edges)
+            .filter_map(|(key, edge)| Some((key, edge?)))
+    }
+    /// Read the last stable value of the input named `key`, or `None` if it is not in the map.
+    pub fn read_stable(&self, key: K) -> Option<bool> {
+        let index = self.keys.iter().position(|k| *k == key)?;
+        Some(self.debouncers[index].read_stable())
+    }
+}
+
+/// Declares an [`InputMap`] from a debounce time and a list of keys.
+///
+/// ```ignore
+/// let mut keypad: InputMap<Mono, Key, 3> = input_map!(10.millis(); Key::Up, Key::Down, Key::Ok);
+/// ```
+#[macro_export]
+macro_rules! input_map {
+    ($debounce_time:expr; $($key:expr),+ $(,)?) => {
+        $crate::InputMap::new([$($key),+], $debounce_time)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Key {
+        Up,
+        Down,
+        Ok,
+    }
+
+    #[test]
+    fn test_named_edges() {
+        run_test(|_| {
+            let mut keypad: InputMap<MockMonotonic, Key, 3> =
+                crate::input_map!(10.millis(); Key::Up, Key::Down, Key::Ok);
+            assert_eq!(keypad.update([false, true, true]).count(), 0);
+            MockMonotonic::add(11.millis());
+            let mut edges = keypad.update([false, true, true]);
+            assert_eq!(edges.next(), Some((Key::Down, Edge::Rising)));
+            assert_eq!(edges.next(), Some((Key::Ok, Edge::Rising)));
+            assert_eq!(edges.next(), None);
+            assert_eq!(keypad.read_stable(Key::Up), Some(false));
+            assert_eq!(keypad.read_stable(Key::Ok), Some(true));
+        });
+    }
+}
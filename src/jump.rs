@@ -0,0 +1,128 @@
+use core::ops::Sub;
+
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Debouncer that accepts large changes faster than small ones.
+///
+/// If a new value differs from the stable value by more than a threshold, the shorter fast
+/// debounce time applies instead of the regular one. Small jitter is still fully debounced,
+/// while unambiguous jumps are passed through quickly. A fast debounce time of zero bypasses
+/// debouncing for large jumps entirely.
+///
+/// The distance between two values is computed with `PartialOrd` and `Sub`, so this is meant for
+/// numeric types like ADC readings.
+pub struct JumpDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    debounce_time: M::Duration,
+    fast_time: M::Duration,
+    threshold: T,
+}
+
+impl<M, T> JumpDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value, using `fast_time` for changes larger
+    /// than `threshold`.
+    pub fn new(
+        initial_value: T,
+        debounce_time: M::Duration,
+        fast_time: M::Duration,
+        threshold: T,
+    ) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            debounce_time,
+            fast_time,
+            threshold,
+        }
+    }
+}
+impl<M, T> JumpDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state, using `fast_time` for changes
+    /// larger than `threshold`. The first value always uses the regular debounce time.
+    pub fn new_unknown(debounce_time: M::Duration, fast_time: M::Duration, threshold: T) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            debounce_time,
+            fast_time,
+            threshold,
+        }
+    }
+}
+impl<M, T, V> JumpDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + PartialOrd + Sub<Output = T> + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Checks if `value` differs from the stable value by more than the threshold.
+    pub fn is_jump(&self, value: T) -> bool {
+        self.debouncer
+            .stable_value_raw()
+            .try_get()
+            .is_some_and(|stable| {
+                let distance = if value > stable {
+                    value - stable
+                } else {
+                    stable - value
+                };
+                distance > self.threshold
+            })
+    }
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        let debounce_time = if self.is_jump(new_value) {
+            self.fast_time
+        } else {
+            self.debounce_time
+        };
+        self.debouncer.set_debounce_time(debounce_time);
+        self.debouncer.update(new_value)
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_small_jitter() {
+        run_test(|_| {
+            let mut adc =
+                JumpDebouncer::<MockMonotonic, u16>::new(1000, 20.millis(), 2.millis(), 100);
+            adc.update(1050);
+            MockMonotonic::add(5.millis());
+            assert!(!adc.update(1050).transitioned());
+            MockMonotonic::add(16.millis());
+            assert!(adc.update(1050).transitioned_to(&1050));
+        });
+    }
+
+    #[test]
+    fn test_big_jump() {
+        run_test(|_| {
+            let mut adc =
+                JumpDebouncer::<MockMonotonic, u16>::new(1000, 20.millis(), 2.millis(), 100);
+            assert!(adc.is_jump(500));
+            adc.update(500);
+            MockMonotonic::add(3.millis());
+            assert!(adc.update(500).transitioned_to(&500));
+        });
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Debouncer that latches the most recent transition until it is taken.
+///
+/// The transition is latched independently of the current state, so a caller that checks
+/// infrequently still sees that a transition happened, see [`LatchingDebouncer::take_transition`].
+pub struct LatchingDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    latched: Option<(T, V)>,
+}
+
+impl<M, T> LatchingDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value.
+    pub fn new(initial_value: T, debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            latched: None,
+        }
+    }
+}
+impl<M, T> LatchingDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state.
+    pub fn new_unknown(debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            latched: None,
+        }
+    }
+}
+impl<M, T, V> LatchingDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        let previous_stable = self.debouncer.stable_value_raw();
+        let state = self.debouncer.update(new_value);
+        if state.transitioned() {
+            self.latched = Some((new_value, previous_stable));
+        }
+        state
+    }
+    /// Returns the most recent transition as `(to, from)` and clears it, so each transition is
+    /// returned at most once.
+    ///
+    /// If several transitions happened since the last call, only the most recent one is returned.
+    pub fn take_transition(&mut self) -> Option<(T, V::V)> {
+        self.latched.take().map(|(to, from)| (to, *from))
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_take_transition() {
+        run_test(|_| {
+            let mut debouncer = LatchingDebouncer::<MockMonotonic, u8>::new(0, 10.millis());
+            assert_eq!(debouncer.take_transition(), None);
+            debouncer.update(1);
+            MockMonotonic::add(11.millis());
+            debouncer.update(1);
+            debouncer.update(2);
+            MockMonotonic::add(11.millis());
+            debouncer.update(2);
+            debouncer.update(2);
+            assert_eq!(debouncer.take_transition(), Some((2, 1)));
+            assert_eq!(debouncer.take_transition(), None);
+        });
+    }
+}
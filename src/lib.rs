@@ -28,11 +28,14 @@
 //!         State::Stable { value } => println!("Stable value: {:?}", value),
 //!         State::Unstable { stable, most_recent } => println!("Unstable - Stable: {:?}, Current: {:?}", stable, most_recent),
 //!         State::Transitioned { stable, previous_stable } => println!("Transitioned to {:?} from {:?}", stable, previous_stable),
+//!         State::Initialized { value } => println!("Initialized to {:?}", value),
 //!     }
 //!     delay(2.millis());
 //! }
 //! ```
 //!
+//! The [`match_state!`] macro provides a shorter way to handle all variants of [`State`].
+//!
 //! The crate is designed to be as generic as possible, working with any data type that implements `PartialEq` and `Copy`.
 //!
 //! ## Features
@@ -44,15 +47,163 @@
 #![no_std]
 #![deny(missing_docs)]
 
+mod adaptive;
+mod backoff;
+mod bank;
+mod buffered;
+mod builder;
+mod cached;
+mod cascade;
+mod changes;
+mod coherent;
+mod combined;
+mod count;
+mod debounce;
+mod delta_time;
+mod differential;
+mod duration;
+mod fallible;
+mod fixed;
+mod history;
+mod hold;
+mod hybrid;
+mod input_map;
+mod jump;
+mod latch;
+mod merge;
+#[cfg(test)]
+mod mock;
+mod observer;
+mod port;
+#[cfg_attr(docsrs, doc(cfg(feature = "ehal1")))]
+#[cfg(feature = "ehal1")]
+mod pull;
+mod pulse;
+mod pwm;
+mod quiet;
+mod range;
+mod signal_loss;
+mod snap;
+mod stateless;
+mod stats;
+mod sticky;
+mod stuck;
+#[cfg_attr(docsrs, doc(cfg(feature = "cortex-m")))]
+#[cfg(feature = "cortex-m")]
+mod systick;
+mod table;
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub mod testing;
+#[cfg_attr(docsrs, doc(cfg(feature = "fugit")))]
+#[cfg(feature = "fugit")]
+mod tick;
 mod timed;
+mod toggle;
+mod tracking;
+mod uptime;
+mod validated;
 mod value;
+mod warmup;
 mod wrapper;
 
-use core::{convert::Infallible, ops::Add};
+use core::{convert::Infallible, fmt, ops::Add};
 
-pub use timed::TimedDebouncer;
-pub(crate) use value::{InitializedValue, UninitializedValue, Value};
-pub use wrapper::{DebouncedInput, Input, IntoDebounced};
+pub use adaptive::AdaptiveDebouncer;
+pub use backoff::BackoffDebouncer;
+pub use bank::{DebounceAll, ReadAll};
+pub use buffered::BufferedDebouncedInput;
+pub use builder::{AnyDebouncer, DebouncerBuilder, DebouncerKind};
+pub use cached::CachedDebouncer;
+pub use cascade::Cascade;
+pub use changes::{ChangeCounter, Changes};
+pub use coherent::CoherentArray;
+pub use combined::{CombinedDebouncer, Reduction};
+pub use count::CountDebouncer;
+pub use debounce::Debounce;
+pub use delta_time::DeltaTimeDebouncer;
+pub use differential::Differential;
+pub use duration::{
+    convert_duration, worst_case_latency, NonZeroDuration, PhysicalDuration, TickDuration,
+};
+pub use fallible::{ErrorPolicy, FallibleDebouncedInput};
+pub use fixed::FixedDebouncer;
+pub use history::HistoryDebouncer;
+pub use hold::MinHoldDebouncer;
+pub use hybrid::HybridDebouncer;
+pub use input_map::InputMap;
+pub use jump::JumpDebouncer;
+pub use latch::LatchingDebouncer;
+pub use merge::{merge, MergedState};
+#[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+#[cfg(feature = "log")]
+pub use observer::LogObserver;
+pub use observer::{NoopObserver, TransitionEvent, TransitionObserver};
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+#[cfg(feature = "heapless")]
+pub use observer::{OverflowPolicy, QueueObserver};
+pub use port::{PortDebouncer, PortMask};
+#[cfg_attr(docsrs, doc(cfg(feature = "ehal1")))]
+#[cfg(feature = "ehal1")]
+pub use pull::{Pull, PullDebouncedInput};
+pub use pulse::PulseWidthDebouncer;
+pub use pwm::{IntoPwmLevel, PwmInput};
+pub use quiet::QuietDetector;
+pub use range::RangeDebouncer;
+pub use signal_loss::SignalLossDebouncer;
+pub use snap::Snap;
+pub use stateless::{step, DebounceState};
+pub use stats::{DebounceStats, StatsDebouncer};
+pub use sticky::StickyDebouncer;
+pub use stuck::StuckDebouncer;
+#[cfg_attr(docsrs, doc(cfg(feature = "cortex-m")))]
+#[cfg(feature = "cortex-m")]
+pub use systick::SysTickMonotonic;
+pub use table::TableDebouncer;
+#[cfg_attr(docsrs, doc(cfg(feature = "fugit")))]
+#[cfg(feature = "fugit")]
+pub use tick::{TickFnMonotonic, TickSource};
+pub use timed::{ClockRebase, NotStartedError, Outcome, TimedDebouncer};
+pub use toggle::Toggle;
+pub use tracking::TrackingDebouncer;
+pub use uptime::UptimeDebouncer;
+pub use validated::ValidatedDebouncer;
+pub use value::{InitializedValue, UninitializedValue, Value};
+pub use warmup::WarmupDebouncer;
+pub use wrapper::{ActiveLow, DebouncedInput, Input, IntoDebounced, Inverted, UnwrapInfallible};
+
+/// Returns the names of the cargo features this crate was compiled with.
+///
+/// This can be used to report the build configuration, e.g. in a diagnostics menu.
+pub const fn enabled_features() -> &'static [&'static str] {
+    &[
+        #[cfg(feature = "rtic-time")]
+        "rtic-time",
+        #[cfg(feature = "ehal0")]
+        "ehal0",
+        #[cfg(feature = "ehal1")]
+        "ehal1",
+        #[cfg(feature = "fugit")]
+        "fugit",
+        #[cfg(feature = "log")]
+        "log",
+        #[cfg(feature = "heapless")]
+        "heapless",
+        #[cfg(feature = "std")]
+        "std",
+        #[cfg(feature = "cortex-m")]
+        "cortex-m",
+    ]
+}
+
+/// [`TimedDebouncer`] with a known initial value, as created by [`TimedDebouncer::new`].
+///
+/// This is what `TimedDebouncer::<M, _>` resolves to, since the value type defaults to
+/// [`InitializedValue`].
+pub type InitDebouncer<M, T> = TimedDebouncer<M, T, InitializedValue<T>>;
+/// [`TimedDebouncer`] with an initially unknown value, as created by
+/// [`TimedDebouncer::new_unknown`].
+pub type UnknownDebouncer<M, T> = TimedDebouncer<M, T, UninitializedValue<T>>;
 
 /// # Monotonic clock definition
 ///
@@ -79,7 +230,7 @@ impl<M: rtic_time::Monotonic> Monotonic for M {
 }
 
 /// Represents the state of a debounced input.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum State<T, V: Value<T = T>> {
     /// Indicates a stable state with a known value.
     Stable {
@@ -100,30 +251,36 @@ pub enum State<T, V: Value<T = T>> {
         /// Old stable value before this transition.
         previous_stable: V::V,
     },
+    /// Indicates that a debouncer that started with an unknown state became stable for the first time.
+    Initialized {
+        /// First stable value.
+        value: T,
+    },
 }
 
 impl<T: Copy, V: Value<T = T>> State<T, V>
 where
-    V::V: Copy + From<T>,
+    V::V: Copy,
 {
     /// Returns the current stable value of the state, if available.
-    pub fn stable(self: &Self) -> V::V {
+    pub fn stable(&self) -> V::V {
         match self {
-            State::Stable { value } => (*value).into(),
+            State::Stable { value } => V::wrap(*value),
             State::Unstable {
                 stable,
                 most_recent: _,
-            } => (*stable).into(),
+            } => *stable,
             State::Transitioned {
                 stable: new_stable,
                 previous_stable: _,
-            } => (*new_stable).into(),
+            } => V::wrap(*new_stable),
+            State::Initialized { value } => V::wrap(*value),
         }
     }
     /// Returns the most recent value of the state, if available. This value is potentially not stable yet.
-    pub fn most_recent(self: &Self) -> V::V {
+    pub fn most_recent(&self) -> V::V {
         match self {
-            State::Stable { value } => (*value).into(),
+            State::Stable { value } => V::wrap(*value),
             State::Unstable {
                 stable: _,
                 most_recent,
@@ -131,22 +288,184 @@ where
             State::Transitioned {
                 stable: new_stable,
                 previous_stable: _,
-            } => (*new_stable).into(),
+            } => V::wrap(*new_stable),
+            State::Initialized { value } => V::wrap(*value),
         }
     }
+    /// Returns the stable and the most recent value as a pair. Both are equal unless the state is
+    /// [`State::Unstable`].
+    pub fn values(&self) -> (V::V, V::V) {
+        (self.stable(), self.most_recent())
+    }
 }
 impl<T, V: Value<T = T>> State<T, V> {
-    /// Checks if the state has transitioned to a new value.
-    pub fn transitioned(self: &Self) -> bool {
+    /// Borrows the current stable value of the state, or returns `None` if it is unknown.
+    ///
+    /// Unlike [`State::stable`] this does not copy the value and has no bounds on `V::V`, which is
+    /// convenient in generic code. It returns `Option<&T>` instead of `&V::V`, since most variants
+    /// store a plain `T` and there is no `V::V` to borrow from. Note that [`Value`] is only
+    /// implemented for `Copy` types, so this does not make non-`Copy` values debounceable.
+    pub fn stable_ref(&self) -> Option<&T> {
+        match self {
+            State::Stable { value } => Some(value),
+            State::Unstable { stable, .. } => V::value_ref(stable),
+            State::Transitioned { stable, .. } => Some(stable),
+            State::Initialized { value } => Some(value),
+        }
+    }
+    /// Borrows the most recent value of the state, or returns `None` if it is unknown.
+    ///
+    /// Like [`State::stable_ref`], this does not copy the value and has no bounds on `V::V`.
+    pub fn most_recent_ref(&self) -> Option<&T> {
         match self {
+            State::Unstable { most_recent, .. } => V::value_ref(most_recent),
+            _ => self.stable_ref(),
+        }
+    }
+    /// Checks if the state has transitioned to a new value.
+    ///
+    /// This is also `true` for [`State::Initialized`], since a new stable value was adopted in
+    /// both cases. Use [`State::initialized`] to tell them apart.
+    pub fn transitioned(&self) -> bool {
+        matches!(
+            self,
             State::Transitioned {
                 stable: _,
                 previous_stable: _,
-            } => true,
+            } | State::Initialized { value: _ }
+        )
+    }
+    /// Checks if the state became stable for the first time.
+    pub fn initialized(&self) -> bool {
+        matches!(self, State::Initialized { value: _ })
+    }
+}
+impl<T: PartialEq, V: Value<T = T>> State<T, V> {
+    /// Checks if the state has just transitioned to `target`.
+    ///
+    /// Like [`State::transitioned`] this includes [`State::Initialized`].
+    pub fn transitioned_to(&self, target: &T) -> bool {
+        match self {
+            State::Transitioned { stable, .. } | State::Initialized { value: stable } => {
+                stable == target
+            }
+            _ => false,
+        }
+    }
+    /// Projects the state to a boolean state that is `true` wherever the value equals `true_when`.
+    ///
+    /// This is intended for types with two meaningful states. Unknown values of an uninitialized
+    /// debouncer are reported as `false`.
+    pub fn as_bool(&self, true_when: &T) -> State<bool, InitializedValue<bool>> {
+        let is_true = |value: &V::V| V::value_ref(value) == Some(true_when);
+        match self {
+            State::Stable { value } => State::Stable {
+                value: value == true_when,
+            },
+            State::Unstable {
+                stable,
+                most_recent,
+            } => State::Unstable {
+                stable: is_true(stable),
+                most_recent: is_true(most_recent),
+            },
+            State::Transitioned {
+                stable,
+                previous_stable,
+            } => State::Transitioned {
+                stable: stable == true_when,
+                previous_stable: is_true(previous_stable),
+            },
+            State::Initialized { value } => State::Initialized {
+                value: value == true_when,
+            },
+        }
+    }
+    /// Checks if the state has just transitioned away from the known stable value `target`.
+    pub fn transitioned_from(&self, target: &T) -> bool {
+        match self {
+            State::Transitioned {
+                previous_stable, ..
+            } => V::value_ref(previous_stable) == Some(target),
             _ => false,
         }
     }
 }
+/// Edge of a boolean signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    /// Transition from `false` to `true`.
+    Rising,
+    /// Transition from `true` to `false`.
+    Falling,
+}
+
+impl<V: Value<T = bool>> State<bool, V> {
+    /// Returns the edge of a transition, or `None` if the state did not transition between two
+    /// known values.
+    pub fn edge(&self) -> Option<Edge> {
+        match self {
+            State::Transitioned { stable: true, .. } => Some(Edge::Rising),
+            State::Transitioned { stable: false, .. } => Some(Edge::Falling),
+            _ => None,
+        }
+    }
+}
+/// Matches a [`State`] with one arm per variant and named bindings.
+///
+/// All four arms are required and must appear in the order `Stable`, `Unstable`, `Transitioned`,
+/// `Initialized`, so the match stays exhaustive and readable even if it grows.
+///
+/// ```ignore
+/// let text = match_state!(debouncer.update(sample), {
+///     Stable(value) => "stable",
+///     Unstable(stable, most_recent) => "bouncing",
+///     Transitioned(stable, previous_stable) => "changed",
+///     Initialized(value) => "initialized",
+/// });
+/// ```
+#[macro_export]
+macro_rules! match_state {
+    ($state:expr, {
+        Stable($value:pat) => $stable_arm:expr,
+        Unstable($stable:pat, $most_recent:pat) => $unstable_arm:expr,
+        Transitioned($new_stable:pat, $previous_stable:pat) => $transitioned_arm:expr,
+        Initialized($initial:pat) => $initialized_arm:expr $(,)?
+    }) => {
+        match $state {
+            $crate::State::Stable { value: $value } => $stable_arm,
+            $crate::State::Unstable {
+                stable: $stable,
+                most_recent: $most_recent,
+            } => $unstable_arm,
+            $crate::State::Transitioned {
+                stable: $new_stable,
+                previous_stable: $previous_stable,
+            } => $transitioned_arm,
+            $crate::State::Initialized { value: $initial } => $initialized_arm,
+        }
+    };
+}
+impl<T: fmt::Display, V: Value<T = T>> fmt::Display for State<T, V>
+where
+    V::V: fmt::Display,
+{
+    /// Formats the state concisely, e.g. `stable(high)` or `unstable(low->high)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            State::Stable { value } => write!(f, "stable({})", value),
+            State::Unstable {
+                stable,
+                most_recent,
+            } => write!(f, "unstable({}->{})", stable, most_recent),
+            State::Transitioned {
+                stable,
+                previous_stable,
+            } => write!(f, "transitioned({}->{})", previous_stable, stable),
+            State::Initialized { value } => write!(f, "initialized({})", value),
+        }
+    }
+}
 impl<T: Copy, V: Value<T = Result<T, Infallible>, V = Result<T, Infallible>>>
     State<Result<T, Infallible>, V>
 {
@@ -172,6 +491,9 @@ impl<T: Copy, V: Value<T = Result<T, Infallible>, V = Result<T, Infallible>>>
                     stable: *stable.as_ref().unwrap_unchecked(),
                     previous_stable: *previous_stable.as_ref().unwrap_unchecked(),
                 },
+                State::Initialized { value } => State::Initialized {
+                    value: *value.as_ref().unwrap_unchecked(),
+                },
             }
         }
     }
@@ -181,3 +503,65 @@ impl<T: Copy, V: Value<T = Result<T, Infallible>, V = Result<T, Infallible>>>
         unsafe { self.stable().unwrap_unchecked() }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_state_wraps_known_values() {
+        run_test(|_| {
+            let mut debouncer = UnknownDebouncer::<MockMonotonic, u8>::new_unknown(10.millis());
+            let state = debouncer.update(1);
+            assert_eq!((state.stable(), state.most_recent()), (None, Some(1)));
+            MockMonotonic::add(11.millis());
+            let state = debouncer.update(1);
+            assert_eq!((state.stable(), state.most_recent()), (Some(1), Some(1)));
+            let state = debouncer.update(1);
+            assert_eq!(state, State::Stable { value: 1 });
+            assert_eq!((state.stable(), state.most_recent()), (Some(1), Some(1)));
+        });
+    }
+
+    #[test]
+    fn test_state_display() {
+        extern crate std;
+        use std::string::ToString;
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8>::new(0, 10.millis());
+            assert_eq!(debouncer.read().to_string(), "stable(0)");
+            assert_eq!(debouncer.update(1).to_string(), "unstable(0->1)");
+            MockMonotonic::add(11.millis());
+            assert_eq!(debouncer.update(1).to_string(), "transitioned(0->1)");
+            let state: State<u8, InitializedValue<u8>> = State::Initialized { value: 2 };
+            assert_eq!(state.to_string(), "initialized(2)");
+        });
+    }
+
+    #[test]
+    fn test_state_refs() {
+        run_test(|_| {
+            let mut debouncer = UnknownDebouncer::<MockMonotonic, u8>::new_unknown(10.millis());
+            let state = debouncer.update(1);
+            assert_eq!(state.stable_ref(), None);
+            assert_eq!(state.most_recent_ref(), Some(&1));
+            MockMonotonic::add(11.millis());
+            let state = debouncer.update(1);
+            assert_eq!(state.stable_ref(), Some(&1));
+            let state = debouncer.update(2);
+            assert_eq!(state.stable_ref(), Some(&1));
+            assert_eq!(state.most_recent_ref(), Some(&2));
+        });
+    }
+
+    #[test]
+    fn test_enabled_features() {
+        let features = enabled_features();
+        assert_eq!(features.contains(&"fugit"), cfg!(feature = "fugit"));
+        assert_eq!(features.contains(&"ehal1"), cfg!(feature = "ehal1"));
+        assert_eq!(features.contains(&"std"), cfg!(feature = "std"));
+        assert!(!features.contains(&"default"));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{State, Value};
+
+/// Merged view of the states of two redundant debouncers, see [`merge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergedState<T> {
+    /// Both debouncers are settled on the same value.
+    Agree(T),
+    /// Both debouncers are settled, but on different values. The values are in the order the
+    /// states were passed to [`merge`].
+    Disagree(T, T),
+    /// At least one debouncer is still settling.
+    Settling,
+}
+
+/// Merges the states of two debouncers reading redundant sensors.
+///
+/// The result only reports a value if both debouncers are settled and agree on it. If both are
+/// settled on different values, both are reported so the caller can tell which sensor is off.
+pub fn merge<T, V>(a: &State<T, V>, b: &State<T, V>) -> MergedState<T>
+where
+    T: PartialEq + Copy,
+    V: Value<T = T>,
+{
+    match (settled(a), settled(b)) {
+        (Some(a), Some(b)) if a == b => MergedState::Agree(a),
+        (Some(a), Some(b)) => MergedState::Disagree(a, b),
+        _ => MergedState::Settling,
+    }
+}
+
+fn settled<T: Copy, V: Value<T = T>>(state: &State<T, V>) -> Option<T> {
+    match state {
+        State::Stable { value } | State::Initialized { value } => Some(*value),
+        State::Transitioned { stable, .. } => Some(*stable),
+        State::Unstable { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InitializedValue, UninitializedValue};
+
+    type S = State<u8, InitializedValue<u8>>;
+
+    #[test]
+    fn test_agree() {
+        let a: S = State::Stable { value: 1 };
+        let b: S = State::Transitioned {
+            stable: 1,
+            previous_stable: 0,
+        };
+        assert_eq!(merge(&a, &b), MergedState::Agree(1));
+    }
+
+    #[test]
+    fn test_disagree() {
+        let a: S = State::Stable { value: 1 };
+        let b: S = State::Stable { value: 2 };
+        assert_eq!(merge(&a, &b), MergedState::Disagree(1, 2));
+        assert_eq!(merge(&b, &a), MergedState::Disagree(2, 1));
+    }
+
+    #[test]
+    fn test_settling() {
+        let a: S = State::Stable { value: 1 };
+        let b: S = State::Unstable {
+            stable: 1,
+            most_recent: 2,
+        };
+        assert_eq!(merge(&a, &b), MergedState::Settling);
+        let unknown = State::<u8, UninitializedValue<u8>>::Unstable {
+            stable: None,
+            most_recent: Some(1),
+        };
+        let initialized = State::Initialized { value: 1 };
+        assert_eq!(merge(&unknown, &initialized), MergedState::Settling);
+    }
+}
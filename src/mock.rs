@@ -0,0 +1,34 @@
+//! Mock monotonic shared by the unit tests.
+extern crate std;
+
+use crate::Monotonic;
+
+pub(crate) struct MockMonotonic;
+static mut NOW: u64 = 0;
+static MUTEX: std::sync::Mutex<()> = std::sync::Mutex::new(());
+impl MockMonotonic {
+    pub fn reset() {
+        unsafe { NOW = 0 }
+    }
+    pub fn add(duration: <Self as Monotonic>::Duration) {
+        unsafe { NOW += duration.ticks() }
+    }
+}
+impl Monotonic for MockMonotonic {
+    type Instant = fugit::TimerInstantU64<1_000_000>;
+    type Duration = fugit::TimerDurationU64<1_000_000>;
+    const ZERO: Self::Instant = Self::Instant::from_ticks(0);
+
+    fn now() -> Self::Instant {
+        if MUTEX.try_lock().is_ok() {
+            panic!("Not locked");
+        }
+        unsafe { Self::Instant::from_ticks(NOW) }
+    }
+}
+
+pub(crate) fn run_test(f: impl FnOnce(std::sync::MutexGuard<()>)) {
+    let lock = MUTEX.lock().unwrap();
+    MockMonotonic::reset();
+    f(lock);
+}
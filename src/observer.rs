@@ -0,0 +1,342 @@
+use crate::{Monotonic, Value};
+
+/// Observer that gets notified whenever a debouncer transitions to a new stable value.
+///
+/// This can be used to log, count or forward transitions without coupling the debouncer to a
+/// specific logging framework. `()` implements this trait by doing nothing, so an unused observer
+/// has no cost.
+pub trait TransitionObserver<M: Monotonic, T, V: Value<T = T>> {
+    /// Called when the stable value changes from `from` to `to` at the instant `at`.
+    /// `from` is unknown if the debouncer was not initialized before.
+    fn on_transition(&mut self, from: V::V, to: T, at: M::Instant);
+    /// Called when the known stable value `from` reverts to unknown at the instant `at`, e.g.
+    /// after the input was lost for the timeout of a
+    /// [`SignalLossDebouncer`](crate::SignalLossDebouncer). Does nothing by default.
+    fn on_loss(&mut self, from: T, at: M::Instant) {
+        let _ = (from, at);
+    }
+}
+
+/// The default observer of a [`TimedDebouncer`](crate::TimedDebouncer), which does nothing.
+///
+/// The observer is a generic parameter rather than a stored trait object, so the call to
+/// [`TransitionObserver::on_transition`] is resolved at compile time. For this zero-sized no-op
+/// observer the call is inlined to nothing and the debouncer does not grow, so code that does not
+/// observe transitions compiles to the same as without the hook.
+pub type NoopObserver = ();
+
+impl<M: Monotonic, T, V: Value<T = T>> TransitionObserver<M, T, V> for () {
+    #[inline(always)]
+    fn on_transition(&mut self, _from: V::V, _to: T, _at: M::Instant) {}
+}
+
+/// Closures can be used as observers, e.g. to forward transitions to a logging framework like
+/// `log` or `defmt`:
+///
+/// ```ignore
+/// let debouncer = TimedDebouncer::<Mono, _>::new(false, 10.millis())
+///     .with_observer(|from, to, _at| log::debug!("input {} -> {}", from, to));
+/// ```
+impl<M, T, V, F> TransitionObserver<M, T, V> for F
+where
+    M: Monotonic,
+    V: Value<T = T>,
+    F: FnMut(V::V, T, M::Instant),
+{
+    #[inline(always)]
+    fn on_transition(&mut self, from: V::V, to: T, at: M::Instant) {
+        self(from, to, at)
+    }
+}
+
+/// Observer that logs every transition through the `log` crate.
+///
+/// The values are formatted with their `Debug` implementation and logged at the configured level,
+/// [`log::Level::Debug`] by default. This is mostly useful for host simulations and `std` targets.
+#[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+#[cfg(feature = "log")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogObserver {
+    level: log::Level,
+}
+
+#[cfg(feature = "log")]
+impl LogObserver {
+    /// Creates an observer that logs transitions at `level`.
+    pub const fn new(level: log::Level) -> Self {
+        Self { level }
+    }
+    /// Returns the level transitions are logged at.
+    pub fn level(&self) -> log::Level {
+        self.level
+    }
+}
+#[cfg(feature = "log")]
+impl Default for LogObserver {
+    fn default() -> Self {
+        Self::new(log::Level::Debug)
+    }
+}
+#[cfg(feature = "log")]
+impl<M, T, V> TransitionObserver<M, T, V> for LogObserver
+where
+    M: Monotonic,
+    T: core::fmt::Debug,
+    V: Value<T = T>,
+    V::V: core::fmt::Debug,
+{
+    fn on_transition(&mut self, from: V::V, to: T, _at: M::Instant) {
+        log::log!(self.level, "transition {:?} -> {:?}", from, to);
+    }
+    fn on_loss(&mut self, from: T, _at: M::Instant) {
+        log::log!(self.level, "transition {:?} -> unknown", from);
+    }
+}
+
+/// A transition of a debouncer, as reported to a [`TransitionObserver`].
+///
+/// This bundles the arguments of [`TransitionObserver::on_transition`] so transitions can be
+/// forwarded through a queue and consumed elsewhere. With the `heapless` feature a
+/// `QueueObserver` pushes them into a `heapless::spsc` queue.
+pub struct TransitionEvent<M: Monotonic, T, V: Value<T = T>> {
+    /// Stable value before the transition.
+    pub from: V::V,
+    /// Stable value after the transition.
+    pub to: T,
+    /// Instant of the transition.
+    pub at: M::Instant,
+}
+
+impl<M: Monotonic, T: Clone, V: Value<T = T>> Clone for TransitionEvent<M, T, V>
+where
+    V::V: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            from: self.from.clone(),
+            to: self.to.clone(),
+            at: self.at,
+        }
+    }
+}
+impl<M: Monotonic, T: Copy, V: Value<T = T>> Copy for TransitionEvent<M, T, V> where V::V: Copy {}
+
+impl<M: Monotonic, T: core::fmt::Debug, V: Value<T = T>> core::fmt::Debug
+    for TransitionEvent<M, T, V>
+where
+    V::V: core::fmt::Debug,
+    M::Instant: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TransitionEvent")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("at", &self.at)
+            .finish()
+    }
+}
+
+/// What a [`QueueObserver`] does with a transition if the queue is full.
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+#[cfg(feature = "heapless")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// The event is dropped and counted, see [`QueueObserver::dropped`].
+    Drop,
+    /// The event is kept and returned by [`QueueObserver::take_overflowed`]. Only the first
+    /// overflowing event is kept; further events are dropped and counted until it was taken.
+    Return,
+}
+
+/// Observer that pushes every transition as a [`TransitionEvent`] into a `heapless::spsc` queue.
+///
+/// This bridges a debouncer to a consumer elsewhere, e.g. a lower priority task, without
+/// allocation:
+///
+/// ```ignore
+/// static mut QUEUE: Queue<TransitionEvent<Mono, bool, InitializedValue<bool>>, 8> = Queue::new();
+/// let (producer, mut consumer) = unsafe { QUEUE.split() };
+/// let mut debouncer = TimedDebouncer::<Mono, _>::new(false, 10.millis())
+///     .with_observer(QueueObserver::new(producer, OverflowPolicy::Drop));
+/// ```
+///
+/// An update never blocks. If the queue is full, the event is handled according to the
+/// [`OverflowPolicy`].
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+#[cfg(feature = "heapless")]
+pub struct QueueObserver<'a, M: Monotonic, T, V: Value<T = T>, const N: usize> {
+    producer: heapless::spsc::Producer<'a, TransitionEvent<M, T, V>, N>,
+    policy: OverflowPolicy,
+    overflowed: Option<TransitionEvent<M, T, V>>,
+    dropped: u32,
+}
+
+#[cfg(feature = "heapless")]
+impl<'a, M: Monotonic, T, V: Value<T = T>, const N: usize> QueueObserver<'a, M, T, V, N> {
+    /// Creates an observer that enqueues transitions into `producer`.
+    pub fn new(
+        producer: heapless::spsc::Producer<'a, TransitionEvent<M, T, V>, N>,
+        policy: OverflowPolicy,
+    ) -> Self {
+        Self {
+            producer,
+            policy,
+            overflowed: None,
+            dropped: 0,
+        }
+    }
+    /// Returns the overflow policy.
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+    /// Returns the number of events that were dropped, because the queue was full.
+    pub fn dropped(&self) -> u32 {
+        self.dropped
+    }
+    /// Returns the event that did not fit into the queue with [`OverflowPolicy::Return`] and
+    /// clears it, so the next overflowing event is kept again.
+    pub fn take_overflowed(&mut self) -> Option<TransitionEvent<M, T, V>> {
+        self.overflowed.take()
+    }
+    /// Returns the producer, e.g. to recover it after the debouncer is no longer needed.
+    pub fn into_producer(self) -> heapless::spsc::Producer<'a, TransitionEvent<M, T, V>, N> {
+        self.producer
+    }
+}
+#[cfg(feature = "heapless")]
+impl<M: Monotonic, T, V: Value<T = T>, const N: usize> TransitionObserver<M, T, V>
+    for QueueObserver<'_, M, T, V, N>
+{
+    fn on_transition(&mut self, from: V::V, to: T, at: M::Instant) {
+        let Err(event) = self.producer.enqueue(TransitionEvent { from, to, at }) else {
+            return;
+        };
+        match self.policy {
+            OverflowPolicy::Return if self.overflowed.is_none() => self.overflowed = Some(event),
+            _ => self.dropped = self.dropped.saturating_add(1),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "log"))]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use crate::TimedDebouncer;
+    use fugit::ExtU64;
+    use std::{string::String, sync::Mutex, vec::Vec};
+
+    static RECORDS: Mutex<Vec<(log::Level, String)>> = Mutex::new(Vec::new());
+
+    struct TestLogger;
+    impl log::Log for TestLogger {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+        fn log(&self, record: &log::Record) {
+            let message = std::format!("{}", record.args());
+            RECORDS.lock().unwrap().push((record.level(), message));
+        }
+        fn flush(&self) {}
+    }
+    static LOGGER: TestLogger = TestLogger;
+
+    #[test]
+    fn test_log_observer() {
+        run_test(|_| {
+            let _ = log::set_logger(&LOGGER);
+            log::set_max_level(log::LevelFilter::Trace);
+            RECORDS.lock().unwrap().clear();
+
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8, _>::new_unknown(10.millis())
+                .with_observer(LogObserver::new(log::Level::Trace));
+            debouncer.update(1);
+            MockMonotonic::add(11.millis());
+            debouncer.update(1);
+            let mut debouncer = debouncer.with_observer(LogObserver::default());
+            debouncer.update(2);
+            MockMonotonic::add(11.millis());
+            debouncer.update(2);
+
+            let records = RECORDS.lock().unwrap();
+            assert_eq!(
+                records.as_slice(),
+                &[
+                    (log::Level::Trace, "transition None -> 1".into()),
+                    (log::Level::Debug, "transition Some(1) -> 2".into()),
+                ]
+            );
+        });
+    }
+}
+
+#[cfg(all(test, feature = "heapless"))]
+mod queue_tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use crate::{InitializedValue, TimedDebouncer};
+    use fugit::ExtU64;
+    use heapless::spsc::Queue;
+
+    type Event = TransitionEvent<MockMonotonic, bool, InitializedValue<bool>>;
+
+    fn toggle<O: TransitionObserver<MockMonotonic, bool, InitializedValue<bool>>>(
+        debouncer: &mut TimedDebouncer<MockMonotonic, bool, InitializedValue<bool>, O>,
+        times: usize,
+    ) {
+        for _ in 0..times {
+            let value = !debouncer.read_stable();
+            debouncer.update(value);
+            MockMonotonic::add(11.millis());
+            assert!(debouncer.update(value).transitioned());
+        }
+    }
+
+    #[test]
+    fn test_queue_observer_drop() {
+        run_test(|_| {
+            // a queue of size 3 holds 2 events
+            let mut queue: Queue<Event, 3> = Queue::new();
+            let (producer, mut consumer) = queue.split();
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis())
+                .with_observer(QueueObserver::new(producer, OverflowPolicy::Drop));
+            toggle(&mut debouncer, 3);
+            assert_eq!(debouncer.observer().dropped(), 1);
+            assert!(debouncer.observer_mut().take_overflowed().is_none());
+
+            let event = consumer.dequeue().unwrap();
+            assert!(!event.from && event.to);
+            assert_eq!(event.at.ticks(), 11_000);
+            let event = consumer.dequeue().unwrap();
+            assert!(event.from && !event.to);
+            assert!(consumer.dequeue().is_none());
+
+            toggle(&mut debouncer, 1);
+            assert!(!consumer.dequeue().unwrap().to);
+        });
+    }
+
+    #[test]
+    fn test_queue_observer_return() {
+        run_test(|_| {
+            let mut queue: Queue<Event, 3> = Queue::new();
+            let (producer, mut consumer) = queue.split();
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis())
+                .with_observer(QueueObserver::new(producer, OverflowPolicy::Return));
+            toggle(&mut debouncer, 4);
+            // the third event is returned, the fourth is dropped
+            assert_eq!(debouncer.observer().dropped(), 1);
+            let event = debouncer.observer_mut().take_overflowed().unwrap();
+            assert!(!event.from && event.to);
+            assert!(debouncer.observer_mut().take_overflowed().is_none());
+            assert_eq!(consumer.len(), 2);
+
+            consumer.dequeue();
+            toggle(&mut debouncer, 2);
+            assert_eq!(consumer.len(), 2);
+            assert!(!debouncer.observer_mut().take_overflowed().unwrap().to);
+        });
+    }
+}
@@ -0,0 +1,141 @@
+use crate::Monotonic;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Bit mask types of a port that can be debounced by a [`PortDebouncer`].
+///
+/// This is implemented for `u8`, `u16` and `u32`.
+pub trait PortMask: sealed::Sealed + Copy + Eq {
+    /// Number of bits of the port.
+    const BITS: usize;
+    /// Array with one entry per bit of the port.
+    #[doc(hidden)]
+    type PerBit<E: Copy>: AsMut<[E]> + AsRef<[E]>;
+    #[doc(hidden)]
+    fn per_bit<E: Copy>(value: E) -> Self::PerBit<E>;
+    #[doc(hidden)]
+    fn to_bits(self) -> u32;
+    #[doc(hidden)]
+    fn from_bits(bits: u32) -> Self;
+}
+
+macro_rules! impl_port_mask {
+    ($($U:ty),+) => {
+        $(
+            impl sealed::Sealed for $U {}
+            impl PortMask for $U {
+                const BITS: usize = <$U>::BITS as usize;
+                type PerBit<E: Copy> = [E; <$U>::BITS as usize];
+                fn per_bit<E: Copy>(value: E) -> Self::PerBit<E> {
+                    [value; <$U>::BITS as usize]
+                }
+                fn to_bits(self) -> u32 {
+                    self as u32
+                }
+                fn from_bits(bits: u32) -> Self {
+                    bits as $U
+                }
+            }
+        )+
+    };
+}
+impl_port_mask!(u8, u16, u32);
+
+/// Debounces every bit of a port, e.g. all pins of a GPIO port or a keyboard matrix row, at once.
+///
+/// Each bit is debounced independently with the same debounce time. The edges of the last update
+/// are available as masks through [`PortDebouncer::rising`] and [`PortDebouncer::falling`].
+pub struct PortDebouncer<M: Monotonic, U: PortMask> {
+    stable: U,
+    last_raw: U,
+    changed_at: U::PerBit<M::Instant>,
+    debounce_time: M::Duration,
+    rising: U,
+    falling: U,
+}
+
+impl<M, U> PortDebouncer<M, U>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    U: PortMask,
+{
+    /// Creates a new [`PortDebouncer`] with a known initial mask.
+    pub fn new(initial_value: U, debounce_time: M::Duration) -> Self {
+        Self {
+            stable: initial_value,
+            last_raw: initial_value,
+            changed_at: U::per_bit(M::ZERO),
+            debounce_time,
+            rising: U::from_bits(0),
+            falling: U::from_bits(0),
+        }
+    }
+    /// Updates all bits with a raw sample of the port and returns the stable mask.
+    pub fn update(&mut self, raw: U) -> U {
+        let now = M::now();
+        let (raw_bits, last_raw) = (raw.to_bits(), self.last_raw.to_bits());
+        let old = self.stable.to_bits();
+        let mut new = old;
+        for (bit, changed_at) in self.changed_at.as_mut().iter_mut().enumerate() {
+            let mask = 1 << bit;
+            if (raw_bits ^ last_raw) & mask != 0 {
+                *changed_at = now;
+            }
+            if (raw_bits ^ old) & mask != 0 && now >= *changed_at + self.debounce_time {
+                new ^= mask;
+            }
+        }
+        self.last_raw = raw;
+        self.stable = U::from_bits(new);
+        self.rising = U::from_bits(new & !old);
+        self.falling = U::from_bits(old & !new);
+        self.stable
+    }
+    /// Read the last stable mask.
+    pub fn read_stable(&self) -> U {
+        self.stable
+    }
+    /// Returns the bits that went from 0 to 1 in the last update.
+    pub fn rising(&self) -> U {
+        self.rising
+    }
+    /// Returns the bits that went from 1 to 0 in the last update.
+    pub fn falling(&self) -> U {
+        self.falling
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_simultaneous_edges() {
+        run_test(|_| {
+            let mut port = PortDebouncer::<MockMonotonic, u8>::new(0b0000_1111, 10.millis());
+            MockMonotonic::add(1.millis());
+            assert_eq!(port.update(0b1010_0101), 0b0000_1111);
+            assert_eq!((port.rising(), port.falling()), (0, 0));
+            MockMonotonic::add(11.millis());
+            assert_eq!(port.update(0b1010_0101), 0b1010_0101);
+            assert_eq!(port.rising(), 0b1010_0000);
+            assert_eq!(port.falling(), 0b0000_1010);
+            assert_eq!(port.update(0b1010_0101), 0b1010_0101);
+            assert_eq!((port.rising(), port.falling()), (0, 0));
+        });
+    }
+
+    #[test]
+    fn test_size_follows_port_width() {
+        use core::mem::size_of;
+        let instants = |bits| bits * size_of::<<MockMonotonic as Monotonic>::Instant>();
+        assert!(size_of::<PortDebouncer<MockMonotonic, u8>>() < instants(16));
+        assert!(size_of::<PortDebouncer<MockMonotonic, u16>>() < instants(32));
+        assert!(size_of::<PortDebouncer<MockMonotonic, u32>>() >= instants(32));
+    }
+}
@@ -0,0 +1,158 @@
+use ehal1::digital::PinState;
+
+use crate::{Input, Monotonic, State, TimedDebouncer, UninitializedValue};
+
+/// Pull resistor configured for a pin, see [`PullDebouncedInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pull {
+    /// Pull-up resistor, a floating pin reads high.
+    Up,
+    /// Pull-down resistor, a floating pin reads low.
+    Down,
+}
+
+impl Pull {
+    /// Returns the level a floating pin reads with this pull resistor.
+    pub fn level(self) -> PinState {
+        match self {
+            Pull::Up => PinState::High,
+            Pull::Down => PinState::Low,
+        }
+    }
+}
+
+/// Debounced input for a pin with a pull resistor, that starts with an unknown state like
+/// [`DebouncedInput::new_settle`](crate::DebouncedInput::new_settle).
+///
+/// A high-impedance input that is not driven yet, e.g. because the device driving it is still
+/// booting, reads as the level of its pull resistor. To not latch this as the initial stable
+/// value, the pull level has to stay for `float_time` instead of `debounce_time` before it is
+/// accepted. The opposite level is accepted after `debounce_time` as usual. Once the first
+/// value settled, both levels use `debounce_time`.
+///
+/// This assumes that `float_time` is longer than the time the driver of the input needs to
+/// start up, and that the pin is configured with the given [`Pull`]. A pin with an `Infallible`
+/// error can be passed wrapped in [`UnwrapInfallible`](crate::UnwrapInfallible).
+pub struct PullDebouncedInput<M: Monotonic, I> {
+    debouncer: TimedDebouncer<M, PinState, UninitializedValue<PinState>>,
+    input: I,
+    pull: Pull,
+    float_time: M::Duration,
+}
+
+impl<M, I> PullDebouncedInput<M, I>
+where
+    I: Input<PinState>,
+    M: Monotonic,
+    M::Duration: Copy,
+{
+    /// Creates a new [`PullDebouncedInput`] by wrapping an [`Input`].
+    pub fn new(input: I, debounce_time: M::Duration, pull: Pull, float_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            input,
+            pull,
+            float_time,
+        }
+    }
+    /// Read the current state of the input.
+    pub fn read(&mut self) -> State<PinState, UninitializedValue<PinState>> {
+        let value = self.input.read();
+        if value == self.pull.level() && self.debouncer.settled_value().is_none() {
+            self.debouncer.update_with_time(value, self.float_time)
+        } else {
+            self.debouncer.update(value)
+        }
+    }
+    /// Read the last stable state of the input.
+    pub fn read_stable(&self) -> Option<PinState> {
+        self.debouncer.read_stable()
+    }
+    /// Returns the configured pull resistor.
+    pub fn pull(&self) -> Pull {
+        self.pull
+    }
+}
+
+#[cfg(all(test, feature = "fugit"))]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use crate::UnwrapInfallible;
+    use core::convert::Infallible;
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use ehal1::digital::{ErrorType, InputPin};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_pull_level_needs_float_time() {
+        static DRIVEN: AtomicBool = AtomicBool::new(false);
+        struct Pin;
+        impl ErrorType for Pin {
+            type Error = Infallible;
+        }
+        impl InputPin for Pin {
+            fn is_high(&mut self) -> Result<bool, Infallible> {
+                // floats high through the pull-up until the driver pulls it low
+                Ok(!DRIVEN.load(Ordering::Relaxed))
+            }
+            fn is_low(&mut self) -> Result<bool, Infallible> {
+                Ok(DRIVEN.load(Ordering::Relaxed))
+            }
+        }
+        run_test(|_| {
+            let mut input = PullDebouncedInput::<MockMonotonic, _>::new(
+                UnwrapInfallible(Pin),
+                5.millis(),
+                Pull::Up,
+                50.millis(),
+            );
+            input.read();
+            MockMonotonic::add(10.millis());
+            assert_eq!(input.read().stable(), None);
+            DRIVEN.store(true, Ordering::Relaxed);
+            input.read();
+            MockMonotonic::add(6.millis());
+            assert_eq!(
+                input.read(),
+                State::Initialized {
+                    value: PinState::Low
+                }
+            );
+            DRIVEN.store(false, Ordering::Relaxed);
+            input.read();
+            MockMonotonic::add(6.millis());
+            assert!(input.read().transitioned_to(&PinState::High));
+        });
+    }
+
+    #[test]
+    fn test_floating_pin_settles_after_float_time() {
+        struct Floating;
+        impl Input<PinState> for Floating {
+            fn read(&mut self) -> PinState {
+                PinState::Low
+            }
+        }
+        run_test(|_| {
+            let mut input = PullDebouncedInput::<MockMonotonic, _>::new(
+                Floating,
+                5.millis(),
+                Pull::Down,
+                50.millis(),
+            );
+            assert_eq!(input.pull(), Pull::Down);
+            input.read();
+            MockMonotonic::add(49.millis());
+            assert_eq!(input.read().stable(), None);
+            MockMonotonic::add(1.millis());
+            assert_eq!(
+                input.read(),
+                State::Initialized {
+                    value: PinState::Low
+                }
+            );
+            assert_eq!(input.read_stable(), Some(PinState::Low));
+        });
+    }
+}
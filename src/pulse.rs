@@ -0,0 +1,105 @@
+use core::ops::Sub;
+
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer};
+
+/// Debouncer that measures how long the stable value stayed at an active level.
+///
+/// Both edges of an active period are timestamped with the instant the new value became stable,
+/// i.e. the last change plus the debounce time, rather than the poll that detected the transition.
+/// Since both edges are delayed by the same debounce time, the measured width does not depend on
+/// how late the transitions were polled. It is still only accurate to one poll interval, as each
+/// edge is seen on the first poll after the raw change. This can be used to tell short taps from
+/// long holds after the fact or to decode pulse width protocols.
+pub struct PulseWidthDebouncer<M: Monotonic, T: Copy> {
+    debouncer: TimedDebouncer<M, T, InitializedValue<T>>,
+    active: T,
+    active_since: Option<M::Instant>,
+    last_active_duration: Option<M::Duration>,
+}
+
+impl<M, T> PulseWidthDebouncer<M, T>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    M::Instant: Sub<Output = M::Duration>,
+    T: PartialEq + Copy,
+{
+    /// Creates a new [`PulseWidthDebouncer`] that measures periods in which the stable value
+    /// equals `active`.
+    pub fn new(initial_value: T, active: T, debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            active,
+            active_since: (initial_value == active).then(M::now),
+            last_active_duration: None,
+        }
+    }
+    /// Updates the debouncer with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, InitializedValue<T>> {
+        let now = M::now();
+        let state = self.debouncer.update_at(new_value, now);
+        if state.transitioned() {
+            let at = self.debouncer.settled_at(now);
+            if state.stable() == self.active {
+                self.active_since = Some(at);
+            } else if let Some(since) = self.active_since.take() {
+                self.last_active_duration = Some(at - since);
+            }
+        }
+        state
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> T {
+        self.debouncer.read_stable()
+    }
+    /// Returns how long the stable value last stayed at the active level before leaving it, or
+    /// `None` if no active period has ended yet.
+    pub fn last_active_duration(&self) -> Option<M::Duration> {
+        self.last_active_duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_last_active_duration() {
+        run_test(|_| {
+            let mut button = PulseWidthDebouncer::<MockMonotonic, _>::new(false, true, 10.millis());
+            button.update(true);
+            MockMonotonic::add(11.millis());
+            assert!(button.update(true).transitioned());
+            assert_eq!(button.last_active_duration(), None);
+            MockMonotonic::add(100.millis());
+            button.update(false);
+            MockMonotonic::add(11.millis());
+            assert!(button.update(false).transitioned());
+            assert_eq!(
+                button.last_active_duration().map(|d| d.ticks()),
+                Some(111_000)
+            );
+        });
+    }
+
+    #[test]
+    fn test_late_polls_do_not_change_width() {
+        run_test(|_| {
+            let mut button = PulseWidthDebouncer::<MockMonotonic, _>::new(false, true, 10.millis());
+            button.update(true);
+            // the press settles after 10 ms but is only polled after 30 ms
+            MockMonotonic::add(30.millis());
+            assert!(button.update(true).transitioned());
+            MockMonotonic::add(70.millis());
+            button.update(false);
+            MockMonotonic::add(11.millis());
+            assert!(button.update(false).transitioned());
+            assert_eq!(
+                button.last_active_duration().map(|d| d.ticks()),
+                Some(100_000)
+            );
+        });
+    }
+}
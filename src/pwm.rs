@@ -0,0 +1,100 @@
+use crate::Input;
+
+/// Input adapter that turns a low frequency PWM line into a boolean level.
+///
+/// Every [`read`](Input::read) samples the wrapped input `window` times and reports `true` once
+/// the share of high samples reaches the high threshold and `false` once it drops to the low
+/// threshold. In between, the previous level is kept. The window should cover at least one full
+/// PWM period. The result can be debounced like any other [`Input`], e.g.
+/// `pwm_line.pwm_level(64).debounce(10.millis())`.
+pub struct PwmInput<I> {
+    input: I,
+    window: u16,
+    low_percent: u8,
+    high_percent: u8,
+    level: bool,
+}
+
+impl<I: Input<bool>> PwmInput<I> {
+    /// Creates a new [`PwmInput`] sampling `window` times per read, with a high threshold of 60%
+    /// and a low threshold of 40%.
+    pub fn new(input: I, window: u16) -> Self {
+        Self {
+            input,
+            window: window.max(1),
+            low_percent: 40,
+            high_percent: 60,
+            level: false,
+        }
+    }
+    /// Changes the duty cycle thresholds in percent. `low_percent` must not exceed `high_percent`.
+    pub fn with_thresholds(mut self, low_percent: u8, high_percent: u8) -> Self {
+        debug_assert!(low_percent <= high_percent);
+        self.low_percent = low_percent;
+        self.high_percent = high_percent;
+        self
+    }
+    /// Returns the number of samples taken per read.
+    pub fn window(&self) -> u16 {
+        self.window
+    }
+    /// Returns the wrapped input.
+    pub fn into_inner(self) -> I {
+        self.input
+    }
+}
+
+impl<I: Input<bool>> Input<bool> for PwmInput<I> {
+    fn read(&mut self) -> bool {
+        let high = (0..self.window).filter(|_| self.input.read()).count() as u32;
+        let duty = high * 100 / self.window as u32;
+        if duty >= self.high_percent as u32 {
+            self.level = true;
+        } else if duty <= self.low_percent as u32 {
+            self.level = false;
+        }
+        self.level
+    }
+}
+
+/// Trait to simplify conversion to [`PwmInput`].
+/// Has a blanket implementation for [`Input<bool>`]
+pub trait IntoPwmLevel: Input<bool> + Sized {
+    /// Convert an Input to a [`PwmInput`] sampling `window` times per read.
+    fn pwm_level(self, window: u16) -> PwmInput<Self>;
+}
+
+impl<I: Input<bool>> IntoPwmLevel for I {
+    fn pwm_level(self, window: u16) -> PwmInput<Self> {
+        PwmInput::new(self, window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Input that is high for the first `duty` of every 10 reads.
+    struct Pwm {
+        duty: u8,
+        tick: u8,
+    }
+    impl Input<bool> for Pwm {
+        fn read(&mut self) -> bool {
+            self.tick = (self.tick + 1) % 10;
+            self.tick < self.duty
+        }
+    }
+
+    #[test]
+    fn test_duty_hysteresis() {
+        let mut line = Pwm { duty: 7, tick: 0 }.pwm_level(20);
+        assert!(line.read());
+        line.input.duty = 5;
+        assert!(line.read());
+        line.input.duty = 3;
+        assert!(!line.read());
+        line.input.duty = 5;
+        assert!(!line.read());
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{Monotonic, State, Value};
+
+/// Detects when `N` debounced inputs have all been stable for some time, e.g. to decide when to
+/// enter a low-power mode.
+///
+/// Feed the state returned by every update of each input with [`QuietDetector::feed`]. Any
+/// unstable or transitioned state counts as activity.
+pub struct QuietDetector<M: Monotonic, const N: usize> {
+    unsettled: [bool; N],
+    last_activity: Option<M::Instant>,
+}
+
+impl<M: Monotonic, const N: usize> QuietDetector<M, N> {
+    /// Creates a new [`QuietDetector`] that considers all inputs quiet until activity is fed.
+    pub fn new() -> Self {
+        Self {
+            unsettled: [false; N],
+            last_activity: None,
+        }
+    }
+    /// Records the latest state of the input `channel`.
+    pub fn feed<T, V: Value<T = T>>(&mut self, channel: usize, state: &State<T, V>) {
+        let unsettled = matches!(state, State::Unstable { .. });
+        self.unsettled[channel] = unsettled;
+        if unsettled || state.transitioned() {
+            self.last_activity = Some(M::now());
+        }
+    }
+    /// Returns the instant of the most recent activity across all inputs, if any.
+    pub fn last_activity(&self) -> Option<M::Instant> {
+        self.last_activity
+    }
+    /// Checks if all inputs are stable and none of them was active for at least `for_duration`.
+    pub fn is_quiet(&self, for_duration: M::Duration) -> bool {
+        !self.unsettled.iter().any(|unsettled| *unsettled)
+            && self
+                .last_activity
+                .is_none_or(|last_activity| M::now() >= last_activity + for_duration)
+    }
+}
+
+impl<M: Monotonic, const N: usize> Default for QuietDetector<M, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use crate::TimedDebouncer;
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_quiet_after_all_settled() {
+        run_test(|_| {
+            let mut quiet = QuietDetector::<MockMonotonic, 2>::new();
+            let mut a = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            let mut b = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            quiet.feed(0, &a.update(false));
+            quiet.feed(1, &b.update(true));
+            assert!(!quiet.is_quiet(0.millis()));
+            MockMonotonic::add(11.millis());
+            quiet.feed(0, &a.update(false));
+            quiet.feed(1, &b.update(true));
+            assert!(quiet.is_quiet(0.millis()));
+            assert!(!quiet.is_quiet(50.millis()));
+            MockMonotonic::add(50.millis());
+            quiet.feed(0, &a.update(false));
+            quiet.feed(1, &b.update(true));
+            assert!(quiet.is_quiet(50.millis()));
+        });
+    }
+}
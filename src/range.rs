@@ -0,0 +1,154 @@
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Debouncer for readings with a valid operating range `[min, max]`.
+///
+/// Samples outside the range are treated as faults and do not take part in debouncing: they
+/// neither become a candidate nor restart the debounce window, so a short spike does not affect
+/// the debounced value. If a fault value is configured with [`RangeDebouncer::with_fault`] and the
+/// readings stay out of range for longer than its timeout, the debouncer transitions to the fault
+/// value. Valid readings are debounced back as usual.
+pub struct RangeDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    min: T,
+    max: T,
+    fault: Option<(T, M::Duration)>,
+    out_of_range_since: Option<M::Instant>,
+}
+
+impl<M, T> RangeDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value that accepts values in `[min, max]`.
+    pub fn new_with_range(initial_value: T, debounce_time: M::Duration, min: T, max: T) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            min,
+            max,
+            fault: None,
+            out_of_range_since: None,
+        }
+    }
+}
+impl<M, T> RangeDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state and accepts values in
+    /// `[min, max]`.
+    pub fn new_unknown_with_range(debounce_time: M::Duration, min: T, max: T) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            min,
+            max,
+            fault: None,
+            out_of_range_since: None,
+        }
+    }
+}
+impl<M: Monotonic, T, V: Value<T = T>> RangeDebouncer<M, T, V> {
+    /// Sets the value the debouncer transitions to once the readings stayed out of range for
+    /// `timeout`.
+    pub fn with_fault(mut self, fault_value: T, timeout: M::Duration) -> Self {
+        self.fault = Some((fault_value, timeout));
+        self
+    }
+    /// Checks if the readings are currently out of range.
+    pub fn is_out_of_range(&self) -> bool {
+        self.out_of_range_since.is_some()
+    }
+    /// Returns the underlying [`TimedDebouncer`].
+    pub fn debouncer(&self) -> &TimedDebouncer<M, T, V> {
+        &self.debouncer
+    }
+}
+impl<M, T, V> RangeDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialOrd + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    ///
+    /// A value outside the range is ignored, unless the readings have been out of range for
+    /// longer than the fault timeout.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        if new_value >= self.min && new_value <= self.max {
+            self.out_of_range_since = None;
+            return self.debouncer.update(new_value);
+        }
+        let now = M::now();
+        let since = *self.out_of_range_since.get_or_insert(now);
+        match self.fault {
+            Some((fault_value, timeout))
+                if now >= since + timeout
+                    && self.debouncer.settled_value() != Some(fault_value) =>
+            {
+                self.debouncer.update(fault_value);
+                self.debouncer.flush()
+            }
+            _ => self.debouncer.read(),
+        }
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    /// Marker for a broken sensor, outside the valid range of `10..=90`.
+    const FAULT: u8 = 0;
+
+    #[test]
+    fn test_spike_does_not_reset_window() {
+        run_test(|_| {
+            let mut debouncer =
+                RangeDebouncer::<MockMonotonic, u8>::new_with_range(20, 10.millis(), 10, 90)
+                    .with_fault(FAULT, 100.millis());
+            debouncer.update(30);
+            MockMonotonic::add(6.millis());
+            assert!(!debouncer.update(255).transitioned());
+            assert!(debouncer.is_out_of_range());
+            MockMonotonic::add(5.millis());
+            assert_eq!(
+                debouncer.update(30),
+                State::Transitioned {
+                    stable: 30,
+                    previous_stable: 20
+                }
+            );
+            assert!(!debouncer.is_out_of_range());
+        });
+    }
+
+    #[test]
+    fn test_sustained_out_of_range_faults() {
+        run_test(|_| {
+            let mut debouncer =
+                RangeDebouncer::<MockMonotonic, u8>::new_with_range(20, 10.millis(), 10, 90)
+                    .with_fault(FAULT, 100.millis());
+            for _ in 0..10 {
+                assert_eq!(debouncer.update(255), State::Stable { value: 20 });
+                MockMonotonic::add(10.millis());
+            }
+            assert!(debouncer.update(255).transitioned_to(&FAULT));
+            assert_eq!(debouncer.update(5), State::Stable { value: FAULT });
+
+            debouncer.update(40);
+            MockMonotonic::add(11.millis());
+            assert!(debouncer.update(40).transitioned_to(&40));
+        });
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    Monotonic, NoopObserver, State, TimedDebouncer, TransitionObserver, UninitializedValue, Value,
+};
+
+/// Debouncer for samples that may be absent, e.g. because a sensor was disconnected.
+///
+/// Present samples are debounced as usual. An absent sample is represented as `None`: once no
+/// sample was present for the loss timeout, the stable value reverts to unknown and the
+/// debouncer starts over as if it was newly created, instead of latching the last good value
+/// forever. The observer is notified of the loss with [`TransitionObserver::on_loss`].
+pub struct SignalLossDebouncer<M: Monotonic, T: Copy, O = NoopObserver> {
+    debouncer: TimedDebouncer<M, T, UninitializedValue<T>, O>,
+    loss_timeout: M::Duration,
+    lost_since: Option<M::Instant>,
+}
+
+impl<M, T> SignalLossDebouncer<M, T>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state and reverts to it after no
+    /// sample was present for `loss_timeout`.
+    pub fn new(debounce_time: M::Duration, loss_timeout: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            loss_timeout,
+            lost_since: None,
+        }
+    }
+}
+impl<M: Monotonic, T: Copy, O> SignalLossDebouncer<M, T, O> {
+    /// Replaces the observer notified on transitions and losses.
+    pub fn with_observer<P>(self, observer: P) -> SignalLossDebouncer<M, T, P> {
+        SignalLossDebouncer {
+            debouncer: self.debouncer.with_observer(observer),
+            loss_timeout: self.loss_timeout,
+            lost_since: self.lost_since,
+        }
+    }
+    /// Returns a reference to the observer.
+    pub fn observer(&self) -> &O {
+        self.debouncer.observer()
+    }
+    /// Get the time without a sample after which the stable value reverts to unknown.
+    pub fn loss_timeout(&self) -> M::Duration
+    where
+        M::Duration: Copy,
+    {
+        self.loss_timeout
+    }
+    /// Changes the time without a sample after which the stable value reverts to unknown.
+    pub fn set_loss_timeout(&mut self, loss_timeout: M::Duration) {
+        self.loss_timeout = loss_timeout;
+    }
+}
+impl<M, T, O> SignalLossDebouncer<M, T, O>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    O: TransitionObserver<M, T, UninitializedValue<T>>,
+{
+    /// Updates the debouncer with a sample that may be absent and returns the current state.
+    ///
+    /// While samples are absent the state is unstable with an unknown most recent value. The
+    /// stable value is kept until the loss timeout has elapsed since the first absent sample.
+    pub fn update(&mut self, sample: Option<T>) -> State<T, UninitializedValue<T>> {
+        let now = M::now();
+        let Some(value) = sample else {
+            let lost_since = *self.lost_since.get_or_insert(now);
+            let known = self.debouncer.last_value_raw().try_get().is_some();
+            if known && now >= lost_since + self.loss_timeout {
+                return self.debouncer.revert_to_unknown(now);
+            }
+            return State::Unstable {
+                stable: self.debouncer.read_stable(),
+                most_recent: None,
+            };
+        };
+        self.lost_since = None;
+        self.debouncer.update_at(value, now)
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> Option<T> {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_signal_loss() {
+        run_test(|_| {
+            let mut debouncer =
+                SignalLossDebouncer::<MockMonotonic, _>::new(10.millis(), 20.millis());
+            debouncer.update(Some(true));
+            MockMonotonic::add(11.millis());
+            assert!(debouncer.update(Some(true)).initialized());
+            assert_eq!(
+                debouncer.update(None),
+                State::Unstable {
+                    stable: Some(true),
+                    most_recent: None
+                }
+            );
+            MockMonotonic::add(15.millis());
+            assert_eq!(debouncer.update(Some(true)), State::Stable { value: true });
+            debouncer.update(None);
+            MockMonotonic::add(15.millis());
+            assert_eq!(debouncer.update(None).stable(), Some(true));
+            MockMonotonic::add(5.millis());
+            assert_eq!(debouncer.update(None).stable(), None);
+            assert_eq!(debouncer.read_stable(), None);
+        });
+    }
+
+    #[test]
+    fn test_loss_is_observed_once() {
+        run_test(|_| {
+            let mut debouncer =
+                SignalLossDebouncer::<MockMonotonic, u8>::new(10.millis(), 20.millis())
+                    .with_observer(Losses(0));
+            debouncer.update(Some(1));
+            MockMonotonic::add(11.millis());
+            debouncer.update(Some(1));
+            for _ in 0..5 {
+                debouncer.update(None);
+                MockMonotonic::add(20.millis());
+            }
+            assert_eq!(debouncer.read_stable(), None);
+            assert_eq!(debouncer.observer().0, 1);
+        });
+    }
+
+    struct Losses(u32);
+    impl TransitionObserver<MockMonotonic, u8, UninitializedValue<u8>> for Losses {
+        fn on_transition(&mut self, _: Option<u8>, _: u8, _: fugit::TimerInstantU64<1_000_000>) {}
+        fn on_loss(&mut self, from: u8, _: fugit::TimerInstantU64<1_000_000>) {
+            assert_eq!(from, 1);
+            self.0 += 1;
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::Input;
+
+/// Input adapter that snaps a raw analog reading to the nearest of `K` reference levels.
+///
+/// [`read`](Input::read) returns the index of the nearest level, which can then be debounced like
+/// any other [`Input`]. This turns e.g. a resistor ladder keypad read by an ADC into a clean
+/// discrete input. If two levels are equally near, the one listed first wins.
+pub struct Snap<I, const K: usize> {
+    input: I,
+    levels: [u16; K],
+}
+
+impl<I: Input<u16>, const K: usize> Snap<I, K> {
+    /// Creates a new [`Snap`] with the given reference levels.
+    pub fn new(input: I, levels: [u16; K]) -> Self {
+        Self { input, levels }
+    }
+    /// Returns the reference levels.
+    pub fn levels(&self) -> &[u16; K] {
+        &self.levels
+    }
+    /// Returns the index of the level nearest to `raw`.
+    pub fn nearest(&self, raw: u16) -> usize {
+        self.levels
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| level.abs_diff(raw))
+            .map_or(0, |(index, _)| index)
+    }
+    /// Returns the wrapped input.
+    pub fn into_inner(self) -> I {
+        self.input
+    }
+}
+
+impl<I: Input<u16>, const K: usize> Input<usize> for Snap<I, K> {
+    fn read(&mut self) -> usize {
+        let raw = self.input.read();
+        self.nearest(raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use crate::IntoDebounced;
+    use fugit::ExtU64;
+
+    impl Input<u16> for &core::cell::Cell<u16> {
+        fn read(&mut self) -> u16 {
+            self.get()
+        }
+    }
+
+    #[test]
+    fn test_snap_ladder() {
+        run_test(|_| {
+            let adc = core::cell::Cell::new(3);
+            let snap = Snap::new(&adc, [0, 1024, 2048, 4095]);
+            assert_eq!(snap.nearest(1500), 1);
+            assert_eq!(snap.nearest(1600), 2);
+            let mut keypad: crate::DebouncedInput<MockMonotonic, _, _> = snap.debounce(10.millis());
+            adc.set(2000);
+            keypad.read();
+            adc.set(2100);
+            MockMonotonic::add(11.millis());
+            assert!(keypad.read().transitioned_to(&2));
+        });
+    }
+}
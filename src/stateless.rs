@@ -0,0 +1,238 @@
+use crate::{InitializedValue, Monotonic, Outcome, State, UninitializedValue, Value};
+
+/// Plain state of a debouncer that is stored by the caller and advanced with [`step`].
+///
+/// This allows keeping the state in a custom memory layout or persisting it. A
+/// [`TimedDebouncer`](crate::TimedDebouncer) holds one of these next to its configuration and
+/// advances it with the same logic as [`step`], see [`TimedDebouncer::debounce_state`](crate::TimedDebouncer::debounce_state).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebounceState<V, I> {
+    pub(crate) stable: V,
+    pub(crate) most_recent: V,
+    pub(crate) last_change_time: I,
+    pub(crate) first_change_in_window: Option<I>,
+    pub(crate) unstable_since: Option<I>,
+    pub(crate) candidate_matches: u32,
+    pub(crate) window_restarts: u32,
+}
+
+impl<T: Copy, I> DebounceState<InitializedValue<T>, I> {
+    /// Creates a new state with a known initial value that has been stable since `since`.
+    pub const fn new(initial_value: T, since: I) -> Self {
+        Self {
+            stable: InitializedValue::new(initial_value),
+            most_recent: InitializedValue::new(initial_value),
+            last_change_time: since,
+            first_change_in_window: None,
+            unstable_since: None,
+            candidate_matches: 0,
+            window_restarts: 0,
+        }
+    }
+}
+impl<T: Copy, I> DebounceState<UninitializedValue<T>, I> {
+    /// Creates a new state with an unknown value.
+    pub fn new_unknown(now: I) -> Self {
+        Self {
+            stable: Default::default(),
+            most_recent: Default::default(),
+            last_change_time: now,
+            first_change_in_window: None,
+            unstable_since: None,
+            candidate_matches: 0,
+            window_restarts: 0,
+        }
+    }
+}
+impl<V: Value, I: Copy> DebounceState<V, I> {
+    /// Returns the current stable value.
+    pub fn stable(&self) -> V::V {
+        self.stable.get()
+    }
+    /// Returns the most recent value.
+    pub fn most_recent(&self) -> V::V {
+        self.most_recent.get()
+    }
+    /// Returns the instant of the last change of the value.
+    pub fn last_change_time(&self) -> I {
+        self.last_change_time
+    }
+}
+impl<T, V, I> DebounceState<V, I>
+where
+    T: Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Adopts `new_value` as the stable value and returns the resulting state.
+    pub(crate) fn settle(&mut self, new_value: T) -> State<T, V> {
+        let previous_stable = self.stable;
+        self.stable = new_value.into();
+        self.most_recent = new_value.into();
+        self.first_change_in_window = None;
+        self.unstable_since = None;
+        self.candidate_matches = 0;
+        if previous_stable.try_get().is_some() {
+            State::Transitioned {
+                stable: new_value,
+                previous_stable: *previous_stable,
+            }
+        } else {
+            State::Initialized { value: new_value }
+        }
+    }
+}
+
+/// Advances a caller-owned [`DebounceState`] with a new value sampled at `now` and returns the
+/// resulting state.
+///
+/// This runs the same algorithm as [`TimedDebouncer::update_at`](crate::TimedDebouncer::update_at)
+/// with the default settings.
+pub fn step<M, T, V>(
+    state: &mut DebounceState<V, M::Instant>,
+    debounce_time: M::Duration,
+    new_value: T,
+    now: M::Instant,
+) -> State<T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    advance::<M, T, V>(state, new_value, now, debounce_time, None, false, true).0
+}
+
+/// The transition logic shared by [`step`] and [`TimedDebouncer`](crate::TimedDebouncer).
+///
+/// `max_unstable_time` and `debounce_return` correspond to the settings of the same name of the
+/// debouncer. A transition after the debounce time additionally requires `allow_transition`.
+pub(crate) fn advance<M, T, V>(
+    state: &mut DebounceState<V, M::Instant>,
+    new_value: T,
+    now: M::Instant,
+    debounce_time: M::Duration,
+    max_unstable_time: Option<M::Duration>,
+    debounce_return: bool,
+    allow_transition: bool,
+) -> (State<T, V>, Outcome)
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    let max_unstable_time_elapsed = |unstable_since: M::Instant| {
+        max_unstable_time.is_some_and(|max_unstable_time| now >= unstable_since + max_unstable_time)
+    };
+    if let Some(last_stable) = state.stable.try_get() {
+        if last_stable == new_value {
+            // value stayed stable or returned to stable
+            let changed = state.most_recent.try_get() != Some(new_value);
+            let mut outcome = if changed {
+                Outcome::NoiseRejected
+            } else {
+                Outcome::NoChange
+            };
+            if changed {
+                state.last_change_time = now;
+                if state.unstable_since.is_some() {
+                    state.window_restarts = state.window_restarts.saturating_add(1);
+                }
+            }
+            state.most_recent = new_value.into();
+            state.candidate_matches = 0;
+            if debounce_return
+                && state.first_change_in_window.is_some()
+                && now < state.last_change_time + debounce_time
+            {
+                // the return to stable is still bouncing
+                if changed {
+                    outcome = Outcome::WindowRestarted;
+                }
+                let unstable = State::Unstable {
+                    stable: *state.stable,
+                    most_recent: V::wrap(new_value),
+                };
+                return (unstable, outcome);
+            }
+            state.first_change_in_window = None;
+            if let Some(unstable_since) = state.unstable_since {
+                if now >= state.last_change_time + debounce_time
+                    || max_unstable_time_elapsed(unstable_since)
+                {
+                    state.unstable_since = None;
+                }
+            }
+            return (State::Stable { value: last_stable }, outcome);
+        }
+    }
+    let outcome = if state.most_recent.try_get() == Some(new_value) {
+        state.candidate_matches = state.candidate_matches.saturating_add(1);
+        Outcome::NoChange
+    } else {
+        // value changed since last update or first value
+        state.last_change_time = now;
+        state.candidate_matches = 1;
+        if state.unstable_since.is_some() {
+            state.window_restarts = state.window_restarts.saturating_add(1);
+        }
+        if state.first_change_in_window.is_some() {
+            Outcome::WindowRestarted
+        } else {
+            Outcome::WindowStarted
+        }
+    };
+    if state.first_change_in_window.is_none() {
+        state.first_change_in_window = Some(state.last_change_time);
+    }
+    if state.unstable_since.is_none() {
+        state.window_restarts = 0;
+    }
+    let unstable_since = *state.unstable_since.get_or_insert(state.last_change_time);
+
+    state.most_recent = new_value.into();
+
+    if (allow_transition && now >= state.last_change_time + debounce_time)
+        || max_unstable_time_elapsed(unstable_since)
+    {
+        // transitioned to a new state
+        (state.settle(new_value), Outcome::Transitioned)
+    } else {
+        // not stable at the moment
+        let unstable = State::Unstable {
+            stable: *state.stable,
+            most_recent: V::wrap(new_value),
+        };
+        (unstable, outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use crate::TimedDebouncer;
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_step_matches_debouncer() {
+        run_test(|_| {
+            let samples = [1, 2, 2, 0, 2, 2, 2, 2, 3, 2, 3, 3, 3, 3];
+            let start = MockMonotonic::now();
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8, _>::new_unknown(10.millis());
+            let mut state = DebounceState::new_unknown(start);
+            for (i, sample) in samples.into_iter().enumerate() {
+                let now = start + (4 * i as u64).millis();
+                assert_eq!(
+                    step::<MockMonotonic, _, _>(&mut state, 10.millis(), sample, now),
+                    debouncer.update_at(sample, now)
+                );
+                assert_eq!(state, debouncer.debounce_state());
+            }
+            assert_eq!(state.stable(), Some(3));
+        });
+    }
+}
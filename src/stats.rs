@@ -0,0 +1,112 @@
+use crate::{
+    InitializedValue, Monotonic, Outcome, State, TimedDebouncer, UninitializedValue, Value,
+};
+
+/// Event counters of a [`StatsDebouncer`], see [`StatsDebouncer::take_stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DebounceStats {
+    /// Number of transitions to a new stable value, including the initialization of an unknown
+    /// value.
+    pub transitions: u32,
+    /// Number of times a pending value was discarded, because the input bounced back to the
+    /// stable value.
+    pub noise_rejections: u32,
+    /// Number of times an open debounce window was restarted by another change.
+    pub window_restarts: u32,
+}
+
+/// Debouncer that counts transitions, rejected noise and window restarts for telemetry.
+pub struct StatsDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    stats: DebounceStats,
+}
+
+impl<M, T> StatsDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value.
+    pub fn new(initial_value: T, debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            stats: DebounceStats::default(),
+        }
+    }
+}
+impl<M, T> StatsDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state.
+    pub fn new_unknown(debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            stats: DebounceStats::default(),
+        }
+    }
+}
+impl<M, T, V> StatsDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        let (state, outcome) = self.debouncer.update_outcome(new_value, true, M::now());
+        let counter = match outcome {
+            Outcome::Transitioned => &mut self.stats.transitions,
+            Outcome::NoiseRejected => &mut self.stats.noise_rejections,
+            Outcome::WindowRestarted => &mut self.stats.window_restarts,
+            Outcome::NoChange | Outcome::WindowStarted => return state,
+        };
+        *counter = counter.saturating_add(1);
+        state
+    }
+    /// Returns all event counters and resets them to zero in one step.
+    ///
+    /// This suits periodic telemetry, as no update can happen between reading and clearing the
+    /// counters.
+    pub fn take_stats(&mut self) -> DebounceStats {
+        core::mem::take(&mut self.stats)
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_take_stats() {
+        run_test(|_| {
+            let mut debouncer = StatsDebouncer::<MockMonotonic, u8, _>::new(0, 10.millis());
+            debouncer.update(1);
+            debouncer.update(0);
+            debouncer.update(1);
+            debouncer.update(2);
+            MockMonotonic::add(11.millis());
+            assert!(debouncer.update(2).transitioned());
+            assert_eq!(
+                debouncer.take_stats(),
+                DebounceStats {
+                    transitions: 1,
+                    noise_rejections: 1,
+                    window_restarts: 1,
+                }
+            );
+            assert_eq!(debouncer.take_stats(), DebounceStats::default());
+        });
+    }
+}
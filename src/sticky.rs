@@ -0,0 +1,121 @@
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Debouncer whose sticky values persist once they became stable.
+///
+/// After a transition to a value for which the predicate returns `true`, all samples are ignored
+/// until [`StickyDebouncer::clear_sticky`] is called. Other values are debounced normally. This is
+/// useful for latching fault codes.
+pub struct StickyDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    sticky_values: fn(&T) -> bool,
+    latched: bool,
+}
+
+impl<M, T> StickyDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value and a predicate for sticky values. The
+    /// initial value never latches.
+    pub fn new_with_sticky_values(
+        initial_value: T,
+        debounce_time: M::Duration,
+        sticky_values: fn(&T) -> bool,
+    ) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            sticky_values,
+            latched: false,
+        }
+    }
+}
+impl<M, T> StickyDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state and a predicate for sticky values.
+    pub fn new_unknown_with_sticky_values(
+        debounce_time: M::Duration,
+        sticky_values: fn(&T) -> bool,
+    ) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            sticky_values,
+            latched: false,
+        }
+    }
+}
+impl<M, T, V> StickyDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    ///
+    /// While a sticky value is latched the new value is ignored.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        if self.latched {
+            return self.debouncer.read();
+        }
+        let state = self.debouncer.update(new_value);
+        if state.transitioned() && (self.sticky_values)(&new_value) {
+            self.latched = true;
+        }
+        state
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+    /// Checks if a sticky value is latched.
+    pub fn is_latched(&self) -> bool {
+        self.latched
+    }
+    /// Releases a latched sticky value, so new values are debounced normally again.
+    pub fn clear_sticky(&mut self) {
+        self.latched = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    /// Fault codes of `0x80` and above latch.
+    fn is_fatal(code: &u8) -> bool {
+        *code >= 0x80
+    }
+
+    #[test]
+    fn test_sticky_until_cleared() {
+        run_test(|_| {
+            let mut fault = StickyDebouncer::<MockMonotonic, u8>::new_with_sticky_values(
+                0,
+                10.millis(),
+                is_fatal,
+            );
+            fault.update(0x81);
+            MockMonotonic::add(11.millis());
+            assert!(fault.update(0x81).transitioned_to(&0x81));
+            assert!(fault.is_latched());
+            fault.update(0x01);
+            MockMonotonic::add(11.millis());
+            assert_eq!(fault.update(0x01), State::Stable { value: 0x81 });
+
+            fault.clear_sticky();
+            fault.update(0x01);
+            MockMonotonic::add(11.millis());
+            assert!(fault.update(0x01).transitioned_to(&0x01));
+            assert!(!fault.is_latched());
+        });
+    }
+}
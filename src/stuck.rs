@@ -0,0 +1,98 @@
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Debouncer that detects inputs which stopped changing, e.g. a failed switch on a line that is
+/// expected to toggle.
+///
+/// The input counts as possibly stuck once it has been stable for longer than the stuck timeout,
+/// see [`TimedDebouncer::stable_for`].
+pub struct StuckDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    stuck_timeout: M::Duration,
+}
+
+impl<M, T> StuckDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value.
+    pub fn new(initial_value: T, debounce_time: M::Duration, stuck_timeout: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            stuck_timeout,
+        }
+    }
+}
+impl<M, T> StuckDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state.
+    pub fn new_unknown(debounce_time: M::Duration, stuck_timeout: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            stuck_timeout,
+        }
+    }
+}
+impl<M: Monotonic, T, V: Value<T = T>> StuckDebouncer<M, T, V>
+where
+    M::Duration: Copy,
+{
+    /// Get the timeout after which a stable input is considered possibly stuck.
+    pub fn stuck_timeout(&self) -> M::Duration {
+        self.stuck_timeout
+    }
+    /// Changes the timeout after which a stable input is considered possibly stuck.
+    pub fn set_stuck_timeout(&mut self, stuck_timeout: M::Duration) {
+        self.stuck_timeout = stuck_timeout;
+    }
+}
+impl<M, T, V> StuckDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        self.debouncer.update(new_value)
+    }
+    /// Checks if the input has been stable for longer than the stuck timeout.
+    pub fn is_possibly_stuck(&self) -> bool {
+        self.debouncer.stable_for(self.stuck_timeout)
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_possibly_stuck() {
+        run_test(|_| {
+            let mut debouncer =
+                StuckDebouncer::<MockMonotonic, _>::new(false, 10.millis(), 500.millis());
+            assert!(!debouncer.is_possibly_stuck());
+            MockMonotonic::add(1_000.millis());
+            assert!(debouncer.is_possibly_stuck());
+            debouncer.update(true);
+            MockMonotonic::add(11.millis());
+            debouncer.update(true);
+            assert!(!debouncer.is_possibly_stuck());
+            MockMonotonic::add(500.millis());
+            assert!(debouncer.is_possibly_stuck());
+        });
+    }
+}
@@ -0,0 +1,112 @@
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::Monotonic;
+
+const SYST_CSR: *mut u32 = 0xE000_E010 as *mut u32;
+const SYST_RVR: *mut u32 = 0xE000_E014 as *mut u32;
+const SYST_CVR: *mut u32 = 0xE000_E018 as *mut u32;
+
+const CSR_ENABLE: u32 = 1 << 0;
+const CSR_TICKINT: u32 = 1 << 1;
+const CSR_CLKSOURCE: u32 = 1 << 2;
+
+/// Wrap counter of the SysTick timer. There is only one SysTick per core, so this is shared by
+/// every `SysTickMonotonic<HZ>`.
+static OVERFLOWS: AtomicU32 = AtomicU32::new(0);
+
+/// [`Monotonic`] for Cortex-M cores without RTIC, backed by the SysTick timer.
+///
+/// `HZ` is the frequency SysTick counts at, i.e. the core clock. The 24 bit SysTick counter wraps
+/// after at most 2^24 ticks, so the wraps are counted in software by calling
+/// [`on_interrupt`](Self::on_interrupt) from the `SysTick` exception handler:
+///
+/// ```ignore
+/// SysTickMonotonic::<48_000_000>::start();
+///
+/// #[exception]
+/// fn SysTick() {
+///     SysTickMonotonic::<48_000_000>::on_interrupt();
+/// }
+/// ```
+///
+/// # Caveats
+///
+/// - The exception must be serviced at least once per SysTick period. If interrupts are masked
+///   for longer than that (about 350 ms at 48 MHz), wraps are lost and time jumps backwards.
+/// - [`now`](Monotonic::now) must not be called from an interrupt with a higher priority than
+///   `SysTick` while the exception is pending, as the pending wrap is not accounted for yet.
+/// - The wrap counter is 32 bit, so the clock itself wraps after 2^56 ticks (about 47 years at
+///   48 MHz).
+/// - SysTick is a single shared resource. Do not use it for anything else, e.g. RTIC's
+///   `SysTick` monotonic, at the same time.
+/// - For the same reason all instantiations share one wrap counter. Only use a single `HZ` in a
+///   program, i.e. always name the same `SysTickMonotonic<HZ>` type.
+///
+/// The registers are accessed at their architecturally fixed addresses rather than through the
+/// `SYST` peripheral of the `cortex-m` crate, so this does not depend on a specific version of
+/// it. Consequently the `SYST` peripheral must not be used while this monotonic is running.
+pub struct SysTickMonotonic<const HZ: u32>;
+
+impl<const HZ: u32> SysTickMonotonic<HZ> {
+    /// Configures SysTick to count at the core clock with the maximum reload value, enables the
+    /// `SysTick` exception and resets the clock to zero.
+    pub fn start() {
+        OVERFLOWS.store(0, Ordering::Relaxed);
+        // SAFETY: These are the architecturally defined SysTick registers present on every
+        // Cortex-M core.
+        unsafe {
+            core::ptr::write_volatile(SYST_RVR, 0x00FF_FFFF);
+            core::ptr::write_volatile(SYST_CVR, 0);
+            core::ptr::write_volatile(SYST_CSR, CSR_CLKSOURCE | CSR_TICKINT | CSR_ENABLE);
+        }
+    }
+    /// Counts a SysTick wrap. Must be called from the `SysTick` exception handler.
+    pub fn on_interrupt() {
+        // Only the exception handler writes the counter, so no read-modify-write is needed,
+        // which keeps this usable on ARMv6-M.
+        let overflows = OVERFLOWS.load(Ordering::Relaxed);
+        OVERFLOWS.store(overflows.wrapping_add(1), Ordering::Release);
+    }
+}
+
+impl<const HZ: u32> Monotonic for SysTickMonotonic<HZ> {
+    type Instant = fugit::TimerInstantU64<HZ>;
+    type Duration = fugit::TimerDurationU64<HZ>;
+    const ZERO: Self::Instant = Self::Instant::from_ticks(0);
+
+    fn now() -> Self::Instant {
+        loop {
+            let before = OVERFLOWS.load(Ordering::Acquire);
+            // SAFETY: See `start`.
+            let (reload, current) = unsafe {
+                (
+                    core::ptr::read_volatile(SYST_RVR),
+                    core::ptr::read_volatile(SYST_CVR),
+                )
+            };
+            // Retry if the exception ran in between, as `current` may belong to either period.
+            if OVERFLOWS.load(Ordering::Acquire) == before {
+                return Self::Instant::from_ticks(ticks(before, reload, current));
+            }
+        }
+    }
+}
+
+/// Combines the wrap counter with the down-counting SysTick value into a tick count.
+fn ticks(overflows: u32, reload: u32, current: u32) -> u64 {
+    let period = u64::from(reload & 0x00FF_FFFF) + 1;
+    u64::from(overflows) * period + (period - 1 - u64::from(current.min(reload)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ticks() {
+        assert_eq!(ticks(0, 0x00FF_FFFF, 0x00FF_FFFF), 0);
+        assert_eq!(ticks(0, 0x00FF_FFFF, 0), 0x00FF_FFFF);
+        assert_eq!(ticks(1, 0x00FF_FFFF, 0x00FF_FFFF), 0x0100_0000);
+        assert_eq!(ticks(3, 999, 899), 3_100);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Debouncer that uses a different debounce time depending on the value that is about to become
+/// stable.
+///
+/// The debounce times are looked up in a fixed table of `K` entries. Values that are not listed
+/// use the default debounce time.
+pub struct TableDebouncer<M: Monotonic, T, const K: usize, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    default_time: M::Duration,
+    table: [(T, M::Duration); K],
+}
+
+impl<M, T, const K: usize> TableDebouncer<M, T, K, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value and a table of per-value debounce times.
+    pub fn new_with_table(
+        initial_value: T,
+        default_time: M::Duration,
+        table: [(T, M::Duration); K],
+    ) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, default_time),
+            default_time,
+            table,
+        }
+    }
+}
+impl<M, T, const K: usize> TableDebouncer<M, T, K, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state and a table of per-value debounce
+    /// times.
+    pub fn new_unknown_with_table(default_time: M::Duration, table: [(T, M::Duration); K]) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(default_time),
+            default_time,
+            table,
+        }
+    }
+}
+impl<M, T, const K: usize, V> TableDebouncer<M, T, K, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T>,
+{
+    /// Returns the debounce time that applies before `value` becomes stable.
+    pub fn debounce_time_for(&self, value: T) -> M::Duration {
+        self.table
+            .iter()
+            .find(|(v, _)| *v == value)
+            .map_or(self.default_time, |(_, time)| *time)
+    }
+    /// Returns the underlying [`TimedDebouncer`].
+    pub fn debouncer(&self) -> &TimedDebouncer<M, T, V> {
+        &self.debouncer
+    }
+}
+impl<M, T, const K: usize, V> TableDebouncer<M, T, K, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        let debounce_time = self.debounce_time_for(new_value);
+        self.debouncer.set_debounce_time(debounce_time);
+        self.debouncer.update(new_value)
+    }
+    /// Reads the current state of the debouncer, updating it with the last known value.
+    pub fn read(&mut self) -> State<T, V> {
+        self.debouncer.read()
+    }
+}
+impl<M, T, const K: usize, V> TableDebouncer<M, T, K, V>
+where
+    M: Monotonic,
+    V: Value<T = T>,
+    V::V: Copy,
+{
+    /// Reads the current stable value, if available. This does not update the internal state and just returns the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Mode {
+        Off,
+        Low,
+        High,
+    }
+
+    #[test]
+    fn test_per_value_debounce_time() {
+        run_test(|_| {
+            let mut debouncer = TableDebouncer::<MockMonotonic, _, 2>::new_with_table(
+                Mode::Off,
+                10.millis(),
+                [(Mode::Low, 20.millis()), (Mode::High, 50.millis())],
+            );
+            debouncer.update(Mode::Low);
+            MockMonotonic::add(11.millis());
+            assert!(!debouncer.update(Mode::Low).transitioned());
+            MockMonotonic::add(10.millis());
+            assert!(debouncer.update(Mode::Low).transitioned());
+
+            debouncer.update(Mode::High);
+            MockMonotonic::add(21.millis());
+            assert!(!debouncer.update(Mode::High).transitioned());
+            MockMonotonic::add(30.millis());
+            assert!(debouncer.update(Mode::High).transitioned());
+
+            debouncer.update(Mode::Off);
+            MockMonotonic::add(11.millis());
+            assert_eq!(
+                debouncer.update(Mode::Off),
+                State::Transitioned {
+                    stable: Mode::Off,
+                    previous_stable: Mode::High
+                }
+            );
+        });
+    }
+}
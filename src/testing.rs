@@ -0,0 +1,77 @@
+//! Monotonics intended for tests on the host.
+extern crate std;
+
+use std::sync::OnceLock;
+
+use crate::Monotonic;
+
+/// Monotonic whose time never advances.
+///
+/// [`Monotonic::now`] always returns [`Monotonic::ZERO`], so the debounce window never elapses
+/// unless the debounce time is zero. This isolates the logic that does not depend on time.
+/// Intended for tests only.
+pub struct FrozenMonotonic;
+
+impl Monotonic for FrozenMonotonic {
+    type Instant = fugit::TimerInstantU64<1_000_000>;
+    type Duration = fugit::TimerDurationU64<1_000_000>;
+    const ZERO: Self::Instant = Self::Instant::from_ticks(0);
+
+    fn now() -> Self::Instant {
+        Self::ZERO
+    }
+}
+
+/// Monotonic backed by [`std::time::Instant`] with microsecond resolution.
+///
+/// Time is measured from the first call to [`Monotonic::now`], which returns 1µs rather than
+/// [`Monotonic::ZERO`] so that it counts as started, e.g. for [`TimedDebouncer::try_new`].
+/// Intended for tests and host-side tools only.
+///
+/// [`TimedDebouncer::try_new`]: crate::TimedDebouncer::try_new
+pub struct StdMonotonic;
+
+impl Monotonic for StdMonotonic {
+    type Instant = fugit::TimerInstantU64<1_000_000>;
+    type Duration = fugit::TimerDurationU64<1_000_000>;
+    const ZERO: Self::Instant = Self::Instant::from_ticks(0);
+
+    fn now() -> Self::Instant {
+        static START: OnceLock<std::time::Instant> = OnceLock::new();
+        let elapsed = START.get_or_init(std::time::Instant::now).elapsed();
+        Self::Instant::from_ticks(elapsed.as_micros() as u64 + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TimedDebouncer;
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_frozen_monotonic_does_not_advance() {
+        assert_eq!(FrozenMonotonic::now(), FrozenMonotonic::ZERO);
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        assert_eq!(FrozenMonotonic::now(), FrozenMonotonic::ZERO);
+        let mut debouncer = TimedDebouncer::<FrozenMonotonic, _>::new(false, 1.millis());
+        debouncer.update(true);
+        assert!(!debouncer.update(true).transitioned());
+    }
+
+    #[test]
+    fn test_std_monotonic_is_monotonic() {
+        let start = StdMonotonic::now();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let later = StdMonotonic::now();
+        assert!(later >= start + 2.millis());
+        assert!(StdMonotonic::now() >= later);
+    }
+
+    #[test]
+    fn test_std_monotonic_counts_as_started() {
+        assert!(StdMonotonic::now() > StdMonotonic::ZERO);
+        assert!(TimedDebouncer::<StdMonotonic, _>::try_new(false, 1.millis()).is_ok());
+        assert!(TimedDebouncer::<StdMonotonic, bool, _>::try_new_unknown(1.millis()).is_ok());
+    }
+}
@@ -0,0 +1,62 @@
+use core::marker::PhantomData;
+
+use crate::Monotonic;
+
+/// Source of raw timer ticks for a [`TickFnMonotonic`].
+pub trait TickSource {
+    /// Returns the current number of ticks since the timer was started.
+    fn ticks() -> u64;
+}
+
+/// [`Monotonic`] running at `HZ` ticks per second, backed by a [`TickSource`].
+///
+/// This avoids writing a full [`Monotonic`] implementation if only a tick counter with a known
+/// rate is available:
+///
+/// ```ignore
+/// struct Timer0;
+/// impl TickSource for Timer0 {
+///     fn ticks() -> u64 {
+///         read_timer0()
+///     }
+/// }
+/// let debouncer = TimedDebouncer::<TickFnMonotonic<Timer0, 32_768>, _>::new(false, 10.millis());
+/// ```
+pub struct TickFnMonotonic<S, const HZ: u32>(PhantomData<S>);
+
+impl<S: TickSource, const HZ: u32> Monotonic for TickFnMonotonic<S, HZ> {
+    type Instant = fugit::TimerInstantU64<HZ>;
+    type Duration = fugit::TimerDurationU64<HZ>;
+    const ZERO: Self::Instant = Self::Instant::from_ticks(0);
+
+    fn now() -> Self::Instant {
+        Self::Instant::from_ticks(S::ticks())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    use super::*;
+    use crate::TimedDebouncer;
+    use fugit::ExtU64;
+
+    static TICKS: AtomicU64 = AtomicU64::new(0);
+
+    struct Counter;
+    impl TickSource for Counter {
+        fn ticks() -> u64 {
+            TICKS.load(Ordering::Relaxed)
+        }
+    }
+
+    #[test]
+    fn test_tick_fn_monotonic() {
+        type Mono = TickFnMonotonic<Counter, 1_000>;
+        let mut debouncer = TimedDebouncer::<Mono, _>::new(false, 10.millis());
+        debouncer.update(true);
+        TICKS.store(11, Ordering::Relaxed);
+        assert!(debouncer.update(true).transitioned());
+    }
+}
@@ -1,15 +1,101 @@
-use crate::{InitializedValue, Monotonic, State, UninitializedValue, Value};
+use core::ops::Sub;
+
+use crate::{
+    stateless::advance, DebounceState, Edge, InitializedValue, Monotonic, NoopObserver,
+    PhysicalDuration, State, TransitionObserver, UninitializedValue, Value,
+};
 
 /// Represents a debouncer for handling signal noise in digital input signals.
 /// It stabilizes the signal over a specified debounce period.
-pub struct TimedDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
-    last_stable: V,
-    last_value: V,
-    last_change_time: M::Instant,
-    debounce_time: M::Duration,
+///
+/// An optional [`TransitionObserver`] `O` is notified on every transition. The default
+/// [`NoopObserver`] does nothing.
+pub struct TimedDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>, O = NoopObserver>
+{
+    state: DebounceState<V, M::Instant>,
+    previous_stable: V,
+    settings: Settings<M::Duration>,
+    observer: O,
+}
+
+/// Configuration of a [`TimedDebouncer`] that is passed to [`advance`] on every update.
+#[derive(Clone, Copy)]
+struct Settings<D> {
+    debounce_time: D,
+    max_unstable_time: Option<D>,
+    debounce_return: bool,
 }
 
-impl<M: Monotonic, T, V: Value<T = T>> defmt::Format for TimedDebouncer<M, T, V> {
+impl<D: Copy> Settings<D> {
+    /// Advances `state` with these settings, see [`advance`].
+    fn advance<M, T, V>(
+        &self,
+        state: &mut DebounceState<V, M::Instant>,
+        new_value: T,
+        allow_transition: bool,
+        now: M::Instant,
+    ) -> (State<T, V>, Outcome)
+    where
+        M: Monotonic<Duration = D>,
+        T: PartialEq + Copy,
+        V: Value<T = T> + Copy + From<T>,
+        V::V: Copy,
+    {
+        advance::<M, T, V>(
+            state,
+            new_value,
+            now,
+            self.debounce_time,
+            self.max_unstable_time,
+            self.debounce_return,
+            allow_transition,
+        )
+    }
+}
+
+/// Error returned by the checked constructors if the [`Monotonic`] does not seem to be running yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotStartedError;
+
+/// Strategy for [`TimedDebouncer::rebase_clock`] after a discontinuity of the [`Monotonic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockRebase<I> {
+    /// Shift all stored instants by the size of the jump, so the time that already elapsed in the
+    /// current debounce window is preserved. `previous_now` is the time right before the jump.
+    PreserveElapsed {
+        /// Time of the clock right before the discontinuity.
+        previous_now: I,
+    },
+    /// Move all stored instants to the new time. A pending value has to stay for the full
+    /// debounce time again, so a jump can never cause a spurious transition.
+    Restart,
+}
+
+/// What an update of a debouncer did, see [`CachedDebouncer::last_outcome`](crate::CachedDebouncer::last_outcome).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The value did not change.
+    NoChange,
+    /// A pending value was discarded, because the value returned to the stable value.
+    NoiseRejected,
+    /// A new value differing from the stable value appeared and opened a debounce window.
+    WindowStarted,
+    /// The value changed while a debounce window was open and restarted it.
+    WindowRestarted,
+    /// The debouncer transitioned to a new stable value.
+    Transitioned,
+}
+
+/// Checks whether the monotonic has advanced past [`Monotonic::ZERO`].
+fn check_started<M: Monotonic>() -> Result<(), NotStartedError> {
+    if M::now() > M::ZERO {
+        Ok(())
+    } else {
+        Err(NotStartedError)
+    }
+}
+
+impl<M: Monotonic, T, V: Value<T = T>, O> defmt::Format for TimedDebouncer<M, T, V, O> {
     fn format(&self, _fmt: defmt::Formatter) {
         todo!()
     }
@@ -22,13 +108,33 @@ where
     M::Duration: Copy,
 {
     /// Creates a new Debouncer with a known initial value.
-    pub fn new(initial_value: T, debounce_time: M::Duration) -> Self {
-        Self {
-            last_stable: InitializedValue::new(initial_value),
-            last_value: InitializedValue::new(initial_value),
-            last_change_time: M::ZERO,
-            debounce_time,
-        }
+    ///
+    /// A zero `debounce_time` is allowed and makes every new value stable immediately.
+    pub const fn new(initial_value: T, debounce_time: M::Duration) -> Self {
+        Self::from_state(DebounceState::new(initial_value, M::ZERO), debounce_time)
+    }
+    /// Creates a new Debouncer with a known initial value, after checking that the monotonic
+    /// has been started.
+    ///
+    /// Returns [`NotStartedError`] if [`Monotonic::now`] still returns [`Monotonic::ZERO`]. Note
+    /// that a monotonic may legitimately report zero right after it was started.
+    pub fn try_new(initial_value: T, debounce_time: M::Duration) -> Result<Self, NotStartedError> {
+        check_started::<M>()?;
+        Ok(Self::new(initial_value, debounce_time))
+    }
+    /// Creates a new Debouncer with a known initial value and a debounce time in milliseconds.
+    ///
+    /// This avoids constructing the duration, e.g. with the `fugit` extension traits.
+    ///
+    /// Unlike [`TimedDebouncer::new`] this is not a `const fn`, since the conversion goes through
+    /// [`PhysicalDuration`] and trait methods cannot be called in a const context. To create a
+    /// debouncer at compile time, pass a duration built by a const constructor such as
+    /// `fugit::MillisDurationU32::millis(10)` to [`TimedDebouncer::new`] instead.
+    pub fn new_ms(initial_value: T, debounce_millis: u64) -> Self
+    where
+        M::Duration: PhysicalDuration,
+    {
+        Self::new(initial_value, M::Duration::from_millis(debounce_millis))
     }
 }
 impl<M, T> TimedDebouncer<M, T, UninitializedValue<T>>
@@ -38,78 +144,353 @@ where
     M::Duration: Copy,
 {
     /// Creates a new Debouncer that starts with an unkown state.
+    ///
+    /// Unlike [`TimedDebouncer::new`] this needs no initial value, so `T` does not have to
+    /// implement `Default`.
+    ///
+    /// A zero `debounce_time` is allowed and makes every new value stable immediately.
     pub fn new_unknown(debounce_time: M::Duration) -> Self {
+        Self::from_state(DebounceState::new_unknown(M::now()), debounce_time)
+    }
+    /// Creates a new Debouncer that starts with an unkown state, after checking that the
+    /// monotonic has been started.
+    ///
+    /// Returns [`NotStartedError`] if [`Monotonic::now`] still returns [`Monotonic::ZERO`]. Note
+    /// that a monotonic may legitimately report zero right after it was started.
+    pub fn try_new_unknown(debounce_time: M::Duration) -> Result<Self, NotStartedError> {
+        check_started::<M>()?;
+        Ok(Self::new_unknown(debounce_time))
+    }
+    /// Creates a new Debouncer that starts with an unkown state and a debounce time in
+    /// milliseconds.
+    ///
+    /// This is not a `const fn` for the same reasons as [`TimedDebouncer::new_ms`]; additionally
+    /// the creation time is read from the monotonic at runtime.
+    pub fn new_unknown_ms(debounce_millis: u64) -> Self
+    where
+        M::Duration: PhysicalDuration,
+    {
+        Self::new_unknown(M::Duration::from_millis(debounce_millis))
+    }
+}
+impl<M, T, V> TimedDebouncer<M, T, V>
+where
+    M: Monotonic,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy,
+{
+    /// Reconstructs a debouncer from its parts, e.g. to restore it after a warm reset.
+    ///
+    /// Callers must uphold the following invariants:
+    /// - `last_change_time` was taken from the same monotonic `M` and is not in the future.
+    /// - `last_value` is unknown only if `last_stable` is unknown as well.
+    ///
+    /// If `last_value` differs from `last_stable` the debounce window continues from
+    /// `last_change_time`. All other settings start out at their defaults.
+    pub fn from_parts(
+        last_stable: V,
+        last_value: V,
+        last_change_time: M::Instant,
+        debounce_time: M::Duration,
+    ) -> Self {
+        let pending = match (last_stable.try_get(), last_value.try_get()) {
+            (Some(stable), Some(value)) => stable != value,
+            (None, value) => value.is_some(),
+            (Some(_), None) => false,
+        };
+        let window = pending.then_some(last_change_time);
+        let state = DebounceState {
+            stable: last_stable,
+            most_recent: last_value,
+            last_change_time,
+            first_change_in_window: window,
+            unstable_since: window,
+            candidate_matches: window.map_or(0, |_| 1),
+            window_restarts: 0,
+        };
+        Self::from_state(state, debounce_time)
+    }
+}
+impl<M: Monotonic, T, V: Value<T = T> + Copy> TimedDebouncer<M, T, V> {
+    /// Creates a debouncer that continues from `state` with default settings.
+    pub(crate) const fn from_state(
+        state: DebounceState<V, M::Instant>,
+        debounce_time: M::Duration,
+    ) -> Self {
         Self {
-            last_stable: Default::default(),
-            last_value: Default::default(),
-            last_change_time: M::now(),
-            debounce_time,
+            previous_stable: state.stable,
+            state,
+            settings: Settings {
+                debounce_time,
+                max_unstable_time: None,
+                debounce_return: false,
+            },
+            observer: (),
         }
     }
 }
-impl<M: Monotonic, T, V: Value<T = T>> TimedDebouncer<M, T, V> {
+impl<M: Monotonic, T, V: Value<T = T>, O> TimedDebouncer<M, T, V, O> {
+    /// Splits the debouncer into the parts accepted by [`TimedDebouncer::from_parts`]:
+    /// `(last_stable, last_value, last_change_time, debounce_time)`.
+    pub fn into_parts(self) -> (V, V, M::Instant, M::Duration) {
+        (
+            self.state.stable,
+            self.state.most_recent,
+            self.state.last_change_time,
+            self.settings.debounce_time,
+        )
+    }
+    /// Returns a copy of the state advanced by [`step`](crate::step), without the configuration.
+    ///
+    /// Unlike [`TimedDebouncer::into_parts`] this includes the progress of the current debounce
+    /// window, e.g. the candidate match count.
+    pub fn debounce_state(&self) -> DebounceState<V, M::Instant>
+    where
+        V: Copy,
+    {
+        self.state
+    }
     /// Get the debounce time.
     pub fn debounce_time(&self) -> M::Duration
     where
         M::Duration: Copy,
     {
-        self.debounce_time
+        self.settings.debounce_time
+    }
+    /// Returns the debounce time in whole milliseconds, e.g. for display.
+    ///
+    /// This requires the duration to implement [`PhysicalDuration`], which is the case for all
+    /// `fugit` durations.
+    pub fn debounce_millis(&self) -> u64
+    where
+        M::Duration: PhysicalDuration,
+    {
+        self.settings.debounce_time.as_millis()
     }
     /// Changes the debounce time.
     pub fn set_debounce_time(&mut self, debounce_time: M::Duration) {
-        self.debounce_time = debounce_time;
+        self.settings.debounce_time = debounce_time;
+    }
+    /// Get the maximum time the input may stay unstable before a decision is forced.
+    pub fn max_unstable_time(&self) -> Option<M::Duration>
+    where
+        M::Duration: Copy,
+    {
+        self.settings.max_unstable_time
+    }
+    /// Sets the maximum time the input may stay unstable before a decision is forced, or `None`
+    /// to wait for the input to settle indefinitely.
+    ///
+    /// The input counts as unstable from the first value differing from the stable value until a
+    /// transition happens or the stable value was held for the debounce time again. Returning to
+    /// the stable value for a shorter time does not end the unstable period. Once the maximum time
+    /// has elapsed the most recent value is accepted: a differing value transitions immediately,
+    /// while the stable value just ends the unstable period.
+    pub fn set_max_unstable_time(&mut self, max_unstable_time: Option<M::Duration>) {
+        self.settings.max_unstable_time = max_unstable_time;
+    }
+    /// Replaces the observer notified on transitions.
+    pub fn with_observer<P>(self, observer: P) -> TimedDebouncer<M, T, V, P> {
+        TimedDebouncer {
+            state: self.state,
+            previous_stable: self.previous_stable,
+            settings: self.settings,
+            observer,
+        }
+    }
+    /// Returns a reference to the observer.
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+    /// Returns a mutable reference to the observer.
+    pub fn observer_mut(&mut self) -> &mut O {
+        &mut self.observer
+    }
+    /// Returns whether a return to the stable value is debounced, see
+    /// [`TimedDebouncer::set_debounce_return_to_stable`].
+    pub fn debounce_return_to_stable(&self) -> bool {
+        self.settings.debounce_return
+    }
+    /// Changes how a return to the stable value while a new value is pending is handled.
+    ///
+    /// By default (`false`) the return cancels the pending value immediately and the state is
+    /// reported as stable again. If set to `true`, the return is treated like any other new
+    /// value: the state stays unstable until the stable value was present for the full debounce
+    /// time, so a bouncy return is not mistaken for a settled input.
+    pub fn set_debounce_return_to_stable(&mut self, debounce_return: bool) {
+        self.settings.debounce_return = debounce_return;
+    }
+    /// Returns how often the debounce window was restarted by a changing value since the current
+    /// or most recent unstable period began.
+    ///
+    /// The count is kept after a transition until the input becomes unstable again, so it can be
+    /// read when the transition is reported.
+    pub fn window_restarts(&self) -> u32 {
+        self.state.window_restarts
+    }
+    /// Returns the instant of the last change of the value.
+    pub(crate) fn last_change_time(&self) -> M::Instant {
+        self.state.last_change_time
+    }
+    /// Returns the instant the current value became stable: the last change plus the debounce
+    /// time, or `now` if it was adopted before that, e.g. forced by the maximum unstable time.
+    ///
+    /// This is only meaningful right after a transition.
+    pub(crate) fn settled_at(&self, now: M::Instant) -> M::Instant
+    where
+        M::Duration: Copy,
+    {
+        now.min(self.state.last_change_time + self.settings.debounce_time)
+    }
+    /// Returns the instant the input became unstable, see [`TimedDebouncer::set_max_unstable_time`].
+    pub(crate) fn unstable_since(&self) -> Option<M::Instant> {
+        self.state.unstable_since
+    }
+    /// Restarts the current debounce window, as if the most recent value had just changed.
+    pub(crate) fn restart_window(&mut self) {
+        self.state.last_change_time = M::now();
+    }
+    /// Returns the instant at which the first value differing from the stable value was seen in
+    /// the current debounce window, or `None` if the input is currently stable.
+    ///
+    /// Unlike the start of the debounce window, this does not advance if the value keeps changing,
+    /// so comparing it to the time of the transition yields the latency caused by debouncing.
+    /// It is reset when a transition happens or the value returns to the stable value.
+    pub fn first_change_in_window(&self) -> Option<M::Instant> {
+        self.state.first_change_in_window
     }
 }
-impl<M, T, V> TimedDebouncer<M, T, V>
+impl<M: Monotonic, T, V: Value<T = T>, O> TimedDebouncer<M, T, V, O>
+where
+    M::Instant: Sub<Output = M::Duration> + Sub<M::Duration, Output = M::Instant>,
+{
+    /// Adjusts the stored instants after the [`Monotonic`] was stepped to `new_now`, e.g. by a
+    /// clock synchronization.
+    ///
+    /// Without this, a jump forward would instantly settle a pending value and a jump backwards
+    /// would keep it pending for longer than the debounce time. See [`ClockRebase`] for the
+    /// available strategies.
+    pub fn rebase_clock(&mut self, new_now: M::Instant, strategy: ClockRebase<M::Instant>) {
+        let rebase = |instant| Self::rebase_instant(instant, new_now, strategy);
+        self.state.last_change_time =
+            Self::rebase_instant(self.state.last_change_time, new_now, strategy);
+        self.state.first_change_in_window = self.state.first_change_in_window.map(rebase);
+        self.state.unstable_since = self.state.unstable_since.map(rebase);
+    }
+    fn rebase_instant(
+        instant: M::Instant,
+        new_now: M::Instant,
+        strategy: ClockRebase<M::Instant>,
+    ) -> M::Instant {
+        match strategy {
+            ClockRebase::PreserveElapsed { previous_now } => new_now - (previous_now - instant),
+            ClockRebase::Restart => new_now,
+        }
+    }
+}
+impl<M, T, V, O> TimedDebouncer<M, T, V, O>
 where
     M: Monotonic,
     M::Duration: Copy,
     T: PartialEq + Copy,
     V: Value<T = T> + Copy + From<T>,
-    V::V: Copy + From<T>,
+    V::V: Copy,
+    O: TransitionObserver<M, T, V>,
 {
     /// Updates the debouncer state with a new value and returns the current state.
-    pub fn update(self: &mut Self, new_value: T) -> State<T, V> {
-        if let Some(last_stable) = self.last_stable.try_get() {
-            if last_stable == new_value {
-                // value stayed stable or returned to stable
-                self.last_value = new_value.into();
-                return State::Stable { value: last_stable };
-            }
-        }
-        if let Some(last_value) = self.last_value.try_get() {
-            if last_value != new_value {
-                // value changed since last update
-                self.last_change_time = M::now();
-            }
-        } else {
-            // first value
-            self.last_change_time = M::now();
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        self.update_gated(new_value, true)
+    }
+
+    /// Like [`TimedDebouncer::update`], but a transition after the debounce time additionally
+    /// requires `allow_transition`. A transition forced by the maximum unstable time is not gated.
+    pub(crate) fn update_gated(&mut self, new_value: T, allow_transition: bool) -> State<T, V> {
+        self.update_outcome(new_value, allow_transition, M::now()).0
+    }
+
+    /// Updates the debouncer like [`TimedDebouncer::update_at`] and additionally returns what the
+    /// update did. This is the path all updates take, so the observer is notified here.
+    pub(crate) fn update_outcome(
+        &mut self,
+        new_value: T,
+        allow_transition: bool,
+        now: M::Instant,
+    ) -> (State<T, V>, Outcome) {
+        let last_stable = self.state.stable;
+        let (state, outcome) =
+            self.settings
+                .advance::<M, T, V>(&mut self.state, new_value, allow_transition, now);
+        if outcome == Outcome::Transitioned {
+            self.record_transition(last_stable, new_value, now);
         }
+        (state, outcome)
+    }
 
-        self.last_value = new_value.into();
+    /// Like [`TimedDebouncer::update`], but uses `debounce_time` instead of the configured debounce
+    /// time for this call only. Subsequent calls use the configured debounce time again.
+    pub fn update_with_time(&mut self, new_value: T, debounce_time: M::Duration) -> State<T, V> {
+        let configured = core::mem::replace(&mut self.settings.debounce_time, debounce_time);
+        let state = self.update(new_value);
+        self.settings.debounce_time = configured;
+        state
+    }
 
-        if M::now() >= self.last_change_time + self.debounce_time {
-            // transitioned to a new state
-            let last_stable = self.last_stable;
-            self.last_stable = new_value.into();
-            State::Transitioned {
-                stable: new_value,
-                previous_stable: *last_stable,
-            }
-        } else {
-            // not stable at the moment
-            State::Unstable {
-                stable: *self.last_stable,
-                most_recent: new_value.into(),
-            }
+    /// Like [`TimedDebouncer::update`], but uses the given instant instead of [`Monotonic::now`].
+    ///
+    /// The instants passed to consecutive calls must not decrease.
+    pub fn update_at(&mut self, new_value: T, now: M::Instant) -> State<T, V> {
+        self.update_outcome(new_value, true, now).0
+    }
+
+    /// Checks if calling [`TimedDebouncer::update`] with `new_value` right now would transition,
+    /// without changing the debouncer or notifying the observer.
+    ///
+    /// This advances a copy of the state with the same settings as [`TimedDebouncer::update`], so
+    /// the maximum unstable time and a debounced return to the stable value are taken into account.
+    pub fn would_transition(&self, new_value: T) -> bool {
+        let mut state = self.state;
+        self.settings
+            .advance::<M, T, V>(&mut state, new_value, true, M::now())
+            .0
+            .transitioned()
+    }
+
+    /// Adopts `new_value` as the stable value at `now` and notifies the observer.
+    fn transition(&mut self, new_value: T, now: M::Instant) -> State<T, V> {
+        let last_stable = self.state.stable;
+        let state = self.state.settle(new_value);
+        self.record_transition(last_stable, new_value, now);
+        state
+    }
+
+    /// Updates everything besides the [`DebounceState`] after a transition from `last_stable`.
+    fn record_transition(&mut self, last_stable: V, new_value: T, now: M::Instant) {
+        self.previous_stable = last_stable;
+        self.observer.on_transition(*last_stable, new_value, now);
+    }
+
+    /// Adopts the pending value at `now`, see [`TimedDebouncer::flush`], or returns `None` if no
+    /// value is pending.
+    pub(crate) fn transition_pending(&mut self, now: M::Instant) -> Option<State<T, V>> {
+        let pending = self.pending_value()?;
+        Some(self.transition(pending, now))
+    }
+
+    /// Immediately accepts the pending value as stable, as if the debounce time had elapsed, and
+    /// returns the resulting state. If no value is pending this behaves like [`TimedDebouncer::read`].
+    ///
+    /// This is useful to resolve an in-progress debounce before entering a low-power mode.
+    pub fn flush(&mut self) -> State<T, V> {
+        match self.transition_pending(M::now()) {
+            Some(state) => state,
+            None => self.read(),
         }
     }
 
     /// Reads the current state of the debouncer, updating it with the last known value.
     pub fn read(&mut self) -> State<T, V> {
         // Update the debouncer with the current value to potentially change its state.
-        if let Some(last_value) = self.last_value.try_get() {
+        if let Some(last_value) = self.state.most_recent.try_get() {
             self.update(last_value)
         } else {
             State::Unstable {
@@ -124,7 +505,103 @@ where
         self.read().stable()
     }
 }
-impl<M, T, V> TimedDebouncer<M, T, V>
+impl<M, V, O> TimedDebouncer<M, bool, V, O>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    V: Value<T = bool> + Copy + From<bool>,
+    V::V: Copy,
+    O: TransitionObserver<M, bool, V>,
+{
+    /// Updates the debouncer state with a new value and returns the edge if the stable value
+    /// changed.
+    pub fn update_edge(&mut self, raw: bool) -> Option<Edge> {
+        self.update(raw).edge()
+    }
+}
+impl<M, T, O> TimedDebouncer<M, T, UninitializedValue<T>, O>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    O: TransitionObserver<M, T, UninitializedValue<T>>,
+{
+    /// Reverts the stable value to unknown at `now`, as if the debouncer was created with
+    /// [`TimedDebouncer::new_unknown`], and notifies the observer with
+    /// [`TransitionObserver::on_loss`] if a stable value was known. The configuration is kept.
+    pub(crate) fn revert_to_unknown(&mut self, now: M::Instant) -> State<T, UninitializedValue<T>> {
+        let last_stable = self.state.stable;
+        self.state = DebounceState::new_unknown(now);
+        if let Some(from) = last_stable.try_get() {
+            self.previous_stable = last_stable;
+            self.observer.on_loss(from, now);
+        }
+        State::Unstable {
+            stable: None,
+            most_recent: None,
+        }
+    }
+}
+impl<M, T, V, O> TimedDebouncer<M, T, V, O>
+where
+    M: Monotonic,
+    T: Copy,
+    V: Value<T = T> + Copy,
+    V::V: Copy,
+{
+    /// Clears the debounce progress and restarts all timing at `now`, keeping the stable values.
+    fn reset_timing(&mut self, now: M::Instant) {
+        self.state.most_recent = self.state.stable;
+        self.state.last_change_time = now;
+        self.state.first_change_in_window = None;
+        self.state.unstable_since = None;
+        self.state.candidate_matches = 0;
+        self.state.window_restarts = 0;
+    }
+    /// Keeps the current stable value but discards a pending value and restarts the timing.
+    ///
+    /// The stable and previous stable values, the configuration and the observer are kept. The
+    /// most recent value is set to the stable value, the debounce window is restarted at the
+    /// current time and the candidate match count and the window restart count are cleared.
+    ///
+    /// This is useful after waking up from sleep, when the time since the last update is
+    /// meaningless but the last stable value is still valid.
+    pub fn warm_reset(&mut self) {
+        self.reset_timing(M::now());
+    }
+}
+impl<M, T, O> TimedDebouncer<M, T, InitializedValue<T>, O>
+where
+    M: Monotonic,
+    T: Copy,
+{
+    /// Resets the debouncer to the state right after [`TimedDebouncer::new`] with `initial_value`.
+    ///
+    /// In addition to everything cleared by [`TimedDebouncer::warm_reset`], the stable and
+    /// previous stable values are set to `initial_value` and the timing is based on
+    /// [`Monotonic::ZERO`]. The configuration and the observer are kept.
+    pub fn cold_reset(&mut self, initial_value: T) {
+        self.state.stable = InitializedValue::new(initial_value);
+        self.previous_stable = self.state.stable;
+        self.reset_timing(M::ZERO);
+    }
+}
+impl<M, T, O> TimedDebouncer<M, T, UninitializedValue<T>, O>
+where
+    M: Monotonic,
+    T: Copy,
+{
+    /// Resets the debouncer to the unknown state right after [`TimedDebouncer::new_unknown`].
+    ///
+    /// In addition to everything cleared by [`TimedDebouncer::warm_reset`], the stable and
+    /// previous stable values become unknown. The configuration and the observer are kept.
+    pub fn cold_reset(&mut self) {
+        self.state.stable = Default::default();
+        self.previous_stable = Default::default();
+        self.reset_timing(M::now());
+    }
+}
+impl<M, T, V, O> TimedDebouncer<M, T, V, O>
 where
     M: Monotonic,
     V: Value<T = T>,
@@ -132,45 +609,176 @@ where
 {
     /// Reads the current stable value, if available. This does not update the internal state and just returns the last stable value.
     pub fn read_stable(&self) -> V::V {
-        *self.last_stable
+        *self.state.stable
+    }
+    /// Borrows the current stable value. Unlike [`TimedDebouncer::read_stable`] this has no
+    /// `Copy` bound on `V::V`, which is convenient in generic code.
+    pub fn stable_ref(&self) -> &V::V {
+        &self.state.stable
+    }
+    /// Checks if the debouncer was created with a known initial value, i.e. if `V` is
+    /// [`InitializedValue`]. See [`Value::IS_INITIALIZED`].
+    pub fn is_value_known_at_start(&self) -> bool {
+        V::IS_INITIALIZED
+    }
+    /// Returns the stable value, or `None` if no value has settled yet.
+    ///
+    /// This is the same for every value type: always `Some` for an [`InitializedValue`] and `None`
+    /// for an [`UninitializedValue`](crate::UninitializedValue) until the first value settled.
+    pub fn settled_value(&self) -> Option<T> {
+        self.state.stable.try_get()
+    }
+    /// Returns the stable value before the last transition. Before the first transition this is the
+    /// initial value of the debouncer.
+    pub fn previous_stable(&self) -> V::V {
+        *self.previous_stable
+    }
+    /// Returns a copy of the stored stable value wrapper.
+    pub fn stable_value_raw(&self) -> V
+    where
+        V: Copy,
+    {
+        self.state.stable
+    }
+    /// Returns a copy of the stored wrapper of the most recent value.
+    pub fn last_value_raw(&self) -> V
+    where
+        V: Copy,
+    {
+        self.state.most_recent
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use fugit::ExtU64;
-    extern crate std;
-
-    struct MockMonotonic;
-    static mut NOW: u64 = 0;
-    static MUTEX: std::sync::Mutex<()> = std::sync::Mutex::new(());
-    impl MockMonotonic {
-        pub fn reset() {
-            unsafe { NOW = 0 }
+impl<M, T, V, O> TimedDebouncer<M, T, V, O>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq,
+    V: Value<T = T>,
+{
+    /// Checks if a value differing from the stable value is waiting to become stable.
+    fn is_pending(&self) -> bool {
+        match self.state.most_recent.try_get() {
+            Some(last_value) => self.state.stable.try_get() != Some(last_value),
+            None => false,
         }
-        pub fn add(duration: <Self as Monotonic>::Duration) {
-            unsafe { NOW += duration.ticks() }
+    }
+    /// Checks if the input is currently settling, i.e. a value differing from the stable value is
+    /// pending and the debounce time has not elapsed yet.
+    pub fn is_settling(&self) -> bool {
+        let returning =
+            self.settings.debounce_return && self.state.first_change_in_window.is_some();
+        (self.is_pending() || returning)
+            && M::now() < self.state.last_change_time + self.settings.debounce_time
+    }
+    /// Checks if the input has not changed for at least `duration` and no value is pending.
+    ///
+    /// This is useful to decide whether it is safe to enter a low-power mode.
+    pub fn stable_for(&self, duration: M::Duration) -> bool {
+        !self.is_pending() && M::now() >= self.state.last_change_time + duration
+    }
+    /// Returns the time since the input last changed, or zero if a value is pending.
+    pub fn stable_duration(&self) -> M::Duration
+    where
+        M::Instant: Sub<Output = M::Duration>,
+    {
+        let now = M::now();
+        let since = if self.is_pending() {
+            now
+        } else {
+            self.state.last_change_time
+        };
+        now - since
+    }
+    /// Returns the number of consecutive updates the pending value has been seen in, or zero while
+    /// the input is stable. This counts samples on top of the time based debouncing.
+    pub fn candidate_match_count(&self) -> u32 {
+        self.state.candidate_matches
+    }
+    /// Returns the value the debouncer is about to switch to, i.e. the most recent value while it
+    /// differs from the stable value, or `None` while the input is stable.
+    pub fn pending_value(&self) -> Option<T> {
+        if self.is_pending() {
+            self.state.most_recent.try_get()
+        } else {
+            None
         }
     }
-    impl Monotonic for MockMonotonic {
-        type Instant = fugit::TimerInstantU64<1_000_000>;
-        type Duration = fugit::TimerDurationU64<1_000_000>;
-        const ZERO: Self::Instant = Self::Instant::from_ticks(0);
-
-        fn now() -> Self::Instant {
-            if MUTEX.try_lock().is_ok() {
-                panic!("Not locked");
-            }
-            unsafe { Self::Instant::from_ticks(NOW) }
+    /// Returns the value the debouncer is settling toward: the pending value if there is one,
+    /// otherwise the stable value. This is what a UI could show as "about to be".
+    pub fn settling_toward(&self) -> V::V {
+        if self.is_pending() {
+            self.state.most_recent.get()
+        } else {
+            self.state.stable.get()
         }
     }
-
-    fn run_test(f: impl FnOnce(std::sync::MutexGuard<()>) -> ()) {
-        let lock = MUTEX.lock().unwrap();
-        MockMonotonic::reset();
-        f(lock);
+    /// Returns the time left until the pending value becomes stable, or zero if no value is
+    /// pending or the debounce time has already elapsed.
+    pub fn remaining_debounce_time(&self) -> M::Duration
+    where
+        M::Instant: Sub<Output = M::Duration>,
+    {
+        let now = M::now();
+        let deadline = if self.is_pending() {
+            now.max(self.state.last_change_time + self.settings.debounce_time)
+        } else {
+            now
+        };
+        deadline - now
+    }
+    /// Returns how long ago the pending value should have become stable, or zero if no value is
+    /// pending or its debounce time has not elapsed yet.
+    ///
+    /// The debouncer only transitions when it is polled, so a large value indicates that it is
+    /// polled too slowly compared to the debounce time.
+    pub fn overdue_by(&self) -> M::Duration
+    where
+        M::Instant: Sub<Output = M::Duration>,
+    {
+        let now = M::now();
+        let deadline = if self.is_pending() {
+            now.min(self.state.last_change_time + self.settings.debounce_time)
+        } else {
+            now
+        };
+        now - deadline
+    }
+    /// Returns how far the pending value has progressed towards becoming stable, from `0.0` to
+    /// `1.0`. Returns `1.0` if no value is pending.
+    pub fn settle_progress(&self) -> f32
+    where
+        M::Instant: Sub<Output = M::Duration>,
+        M::Duration: PhysicalDuration,
+    {
+        let debounce_time = self.settings.debounce_time.as_micros();
+        if !self.is_pending() || debounce_time == 0 {
+            return 1.0;
+        }
+        let remaining = self.remaining_debounce_time().as_micros();
+        1.0 - remaining.min(debounce_time) as f32 / debounce_time as f32
     }
+    /// Returns a value suitable for display, which avoids flicker during heavy bounce.
+    ///
+    /// This is the pending value once it has been present for at least `fraction` of the debounce
+    /// time (see [`TimedDebouncer::settle_progress`]), and the stable value otherwise.
+    pub fn displayable_value(&self, fraction: f32) -> V::V
+    where
+        M::Instant: Sub<Output = M::Duration>,
+        M::Duration: PhysicalDuration,
+        T: Copy,
+    {
+        match self.pending_value() {
+            Some(pending) if self.settle_progress() >= fraction => V::wrap(pending),
+            _ => self.state.stable.get(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
 
     #[test]
     fn test_initial_value() {
@@ -202,6 +810,571 @@ mod tests {
             );
             let state = debouncer.update(true);
             assert_eq!(state, State::Stable { value: true });
+            assert!(!debouncer.previous_stable());
+        });
+    }
+
+    #[test]
+    fn test_unknown_value_without_default() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Selector {
+            A,
+            B,
+        }
+        run_test(|_| {
+            let mut debouncer =
+                TimedDebouncer::<MockMonotonic, Selector, _>::new_unknown(10.millis());
+            debouncer.update(Selector::A);
+            debouncer.update(Selector::B);
+            MockMonotonic::add(11.millis());
+            assert_eq!(
+                debouncer.update(Selector::B),
+                State::Initialized { value: Selector::B }
+            );
+        });
+    }
+
+    #[test]
+    fn test_first_change_in_window() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(0u8, 10.millis());
+            assert_eq!(debouncer.first_change_in_window(), None);
+            MockMonotonic::add(1.millis());
+            debouncer.update(1);
+            MockMonotonic::add(5.millis());
+            debouncer.update(2);
+            assert_eq!(
+                debouncer.first_change_in_window(),
+                Some(fugit::TimerInstantU64::<1_000_000>::from_ticks(1_000))
+            );
+            debouncer.update(0);
+            assert_eq!(debouncer.first_change_in_window(), None);
+            debouncer.update(2);
+            MockMonotonic::add(11.millis());
+            assert!(debouncer.update(2).transitioned());
+            assert_eq!(debouncer.first_change_in_window(), None);
+        });
+    }
+
+    #[test]
+    fn test_remaining_debounce_time() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            assert_eq!(debouncer.remaining_debounce_time().ticks(), 0);
+            debouncer.update(true);
+            assert_eq!(debouncer.pending_value(), Some(true));
+            debouncer.update(true);
+            assert_eq!(debouncer.candidate_match_count(), 2);
+            assert!(debouncer.is_settling());
+            MockMonotonic::add(4.millis());
+            assert_eq!(debouncer.remaining_debounce_time().ticks(), 6_000);
+            MockMonotonic::add(20.millis());
+            assert!(!debouncer.is_settling());
+            assert_eq!(debouncer.remaining_debounce_time().ticks(), 0);
+        });
+    }
+
+    #[cfg(feature = "fugit")]
+    #[test]
+    fn test_settle_progress() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            assert_eq!(debouncer.settle_progress(), 1.0);
+            debouncer.update(true);
+            MockMonotonic::add(4.millis());
+            assert!((debouncer.settle_progress() - 0.4).abs() < 1e-6);
+            MockMonotonic::add(20.millis());
+            assert_eq!(debouncer.settle_progress(), 1.0);
+        });
+    }
+
+    #[test]
+    fn test_default_value_type() {
+        run_test(|_| {
+            // `TimedDebouncer::<M, _>` defaults to a known initial value.
+            let debouncer: crate::InitDebouncer<MockMonotonic, bool> =
+                TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            assert!(!debouncer.read_stable());
+            // The value type has to be named or inferred to start with an unknown value.
+            let debouncer: crate::UnknownDebouncer<MockMonotonic, bool> =
+                TimedDebouncer::new_unknown(10.millis());
+            assert_eq!(debouncer.read_stable(), None);
+        });
+    }
+
+    #[test]
+    fn test_transitioned_to_from() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8, _>::new_unknown(10.millis());
+            debouncer.update(1);
+            MockMonotonic::add(11.millis());
+            let state = debouncer.update(1);
+            assert!(state.transitioned_to(&1));
+            assert!(!state.transitioned_from(&1));
+            debouncer.update(2);
+            MockMonotonic::add(11.millis());
+            let state = debouncer.update(2);
+            assert!(state.transitioned_to(&2));
+            assert!(state.transitioned_from(&1));
+            assert!(!state.transitioned_from(&2));
+            assert!(!debouncer.update(2).transitioned_to(&2));
+        });
+    }
+
+    #[cfg(feature = "fugit")]
+    #[test]
+    fn test_displayable_value() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8>::new(0, 10.millis());
+            debouncer.update(1);
+            MockMonotonic::add(3.millis());
+            debouncer.update(2);
+            MockMonotonic::add(4.millis());
+            assert_eq!(debouncer.displayable_value(0.5), 0);
+            MockMonotonic::add(2.millis());
+            debouncer.update(2);
+            assert_eq!(debouncer.displayable_value(0.5), 2);
+        });
+    }
+
+    #[test]
+    fn test_debounce_return_to_stable() {
+        run_test(|_| {
+            let mut instant = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            let mut debounced = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            debounced.set_debounce_return_to_stable(true);
+            // t=0: candidate appears
+            instant.update(true);
+            debounced.update(true);
+            MockMonotonic::add(5.millis());
+            // t=5: brief return to stable
+            assert_eq!(instant.update(false), State::Stable { value: false });
+            assert!(matches!(debounced.update(false), State::Unstable { .. }));
+            assert!(!instant.is_settling());
+            assert!(debounced.is_settling());
+            MockMonotonic::add(10.millis());
+            // t=15: stable value held for the full debounce time
+            assert_eq!(debounced.update(false), State::Stable { value: false });
+            assert!(!debounced.is_settling());
+        });
+    }
+
+    #[test]
+    fn test_as_bool() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        enum Door {
+            Open,
+            Closed,
+        }
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _, _>::new_unknown(10.millis());
+            assert_eq!(
+                debouncer.update(Door::Open).as_bool(&Door::Open),
+                State::Unstable {
+                    stable: false,
+                    most_recent: true
+                }
+            );
+            MockMonotonic::add(11.millis());
+            debouncer.update(Door::Open);
+            debouncer.update(Door::Closed);
+            MockMonotonic::add(11.millis());
+            assert_eq!(
+                debouncer.update(Door::Closed).as_bool(&Door::Open),
+                State::Transitioned {
+                    stable: false,
+                    previous_stable: true
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn test_window_restarts() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            for value in [true, false, true, false, true] {
+                debouncer.update(value);
+                MockMonotonic::add(1.millis());
+            }
+            MockMonotonic::add(10.millis());
+            assert!(debouncer.update(true).transitioned());
+            assert_eq!(debouncer.window_restarts(), 4);
+            debouncer.update(false);
+            assert_eq!(debouncer.window_restarts(), 0);
+        });
+    }
+
+    #[cfg(feature = "fugit")]
+    #[test]
+    fn test_debounce_millis() {
+        let debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 12_345.micros());
+        assert_eq!(debouncer.debounce_millis(), 12);
+    }
+
+    #[test]
+    fn test_would_transition() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8>::new(0, 10.millis());
+            assert!(!debouncer.would_transition(0));
+            debouncer.update(1);
+            MockMonotonic::add(11.millis());
+            assert!(debouncer.would_transition(1));
+            assert!(!debouncer.would_transition(2));
+            assert_eq!(debouncer.pending_value(), Some(1));
+            assert!(debouncer.update(1).transitioned());
+        });
+    }
+
+    #[test]
+    fn test_warm_reset() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8>::new(0, 10.millis());
+            debouncer.update(1);
+            MockMonotonic::add(11.millis());
+            debouncer.update(1);
+            debouncer.update(2);
+            MockMonotonic::add(6.millis());
+            debouncer.warm_reset();
+            assert_eq!(debouncer.read_stable(), 1);
+            assert_eq!(debouncer.previous_stable(), 0);
+            assert_eq!(debouncer.pending_value(), None);
+            assert_eq!(debouncer.window_restarts(), 0);
+            // the pending value has to start over
+            debouncer.update(2);
+            MockMonotonic::add(6.millis());
+            assert!(!debouncer.update(2).transitioned());
+        });
+    }
+
+    #[test]
+    fn test_cold_reset() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8, _>::new_unknown(10.millis());
+            debouncer.set_max_unstable_time(Some(50.millis()));
+            debouncer.update(1);
+            MockMonotonic::add(11.millis());
+            debouncer.update(1);
+            debouncer.update(2);
+            debouncer.cold_reset();
+            assert_eq!(debouncer.read_stable(), None);
+            assert_eq!(debouncer.previous_stable(), None);
+            assert_eq!(debouncer.pending_value(), None);
+            assert_eq!(
+                debouncer.max_unstable_time().map(|t| t.ticks()),
+                Some(50_000)
+            );
+
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8>::new(0, 10.millis());
+            debouncer.update(1);
+            MockMonotonic::add(11.millis());
+            debouncer.update(1);
+            debouncer.cold_reset(3);
+            assert_eq!(debouncer.read_stable(), 3);
+            assert_eq!(debouncer.previous_stable(), 3);
+        });
+    }
+
+    #[test]
+    fn test_match_state() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8, _>::new_unknown(10.millis());
+            let mut describe = |sample| {
+                crate::match_state!(debouncer.update(sample), {
+                    Stable(value) => (value, 0),
+                    Unstable(_, most_recent) => (most_recent.unwrap(), 1),
+                    Transitioned(stable, _) => (stable, 2),
+                    Initialized(value) => (value, 3),
+                })
+            };
+            assert_eq!(describe(1), (1, 1));
+            MockMonotonic::add(11.millis());
+            assert_eq!(describe(1), (1, 3));
+            assert_eq!(describe(1), (1, 0));
+            describe(2);
+            MockMonotonic::add(11.millis());
+            assert_eq!(describe(2), (2, 2));
+        });
+    }
+
+    #[test]
+    fn test_overdue_by() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            assert_eq!(debouncer.overdue_by().ticks(), 0);
+            debouncer.update(true);
+            MockMonotonic::add(4.millis());
+            assert_eq!(debouncer.overdue_by().ticks(), 0);
+            MockMonotonic::add(13.millis());
+            assert_eq!(debouncer.overdue_by().ticks(), 7_000);
+            debouncer.update(true);
+            assert_eq!(debouncer.overdue_by().ticks(), 0);
+        });
+    }
+
+    #[test]
+    fn test_state_values() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8, _>::new_unknown(10.millis());
+            assert_eq!(debouncer.update(1).values(), (None, Some(1)));
+            MockMonotonic::add(11.millis());
+            assert_eq!(debouncer.update(1).values(), (Some(1), Some(1)));
+        });
+    }
+
+    #[cfg(feature = "fugit")]
+    #[test]
+    fn test_new_ms() {
+        run_test(|_| {
+            let debouncer = TimedDebouncer::<MockMonotonic, _>::new_ms(false, 10);
+            assert_eq!(debouncer.debounce_time().ticks(), 10_000);
+            let debouncer = TimedDebouncer::<MockMonotonic, bool, _>::new_unknown_ms(5);
+            assert_eq!(debouncer.debounce_millis(), 5);
+        });
+    }
+
+    #[test]
+    fn test_const_new() {
+        const DEBOUNCER: TimedDebouncer<MockMonotonic, bool> =
+            TimedDebouncer::new(false, fugit::TimerDurationU64::millis(10));
+        run_test(|_| {
+            let mut debouncer = DEBOUNCER;
+            assert_eq!(debouncer.debounce_time().ticks(), 10_000);
+            assert_eq!(debouncer.read(), State::Stable { value: false });
+        });
+    }
+
+    #[test]
+    fn test_update_with_time() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            debouncer.update(true);
+            MockMonotonic::add(11.millis());
+            assert!(!debouncer.update_with_time(true, 50.millis()).transitioned());
+            assert_eq!(debouncer.debounce_time().ticks(), 10_000);
+            assert!(debouncer.update(true).transitioned());
+        });
+    }
+
+    #[test]
+    fn test_settling_toward() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8, _>::new_unknown(10.millis());
+            assert_eq!(debouncer.settling_toward(), None);
+            debouncer.update(1);
+            assert_eq!(debouncer.settling_toward(), Some(1));
+            MockMonotonic::add(11.millis());
+            debouncer.update(1);
+            debouncer.update(2);
+            assert_eq!(debouncer.settling_toward(), Some(2));
+            debouncer.update(1);
+            assert_eq!(debouncer.settling_toward(), Some(1));
+        });
+    }
+
+    #[test]
+    fn test_is_value_known_at_start() {
+        run_test(|_| {
+            let known = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            assert!(known.is_value_known_at_start());
+            let mut unknown = TimedDebouncer::<MockMonotonic, bool, _>::new_unknown(10.millis());
+            unknown.update(true);
+            MockMonotonic::add(11.millis());
+            unknown.update(true);
+            assert!(!unknown.is_value_known_at_start());
+        });
+    }
+
+    #[test]
+    fn test_settled_value() {
+        run_test(|_| {
+            let known = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            assert_eq!(known.settled_value(), Some(false));
+            let mut unknown = TimedDebouncer::<MockMonotonic, bool, _>::new_unknown(10.millis());
+            assert_eq!(unknown.settled_value(), None);
+            unknown.update(true);
+            assert_eq!(unknown.settled_value(), None);
+            MockMonotonic::add(11.millis());
+            unknown.update(true);
+            assert_eq!(unknown.settled_value(), Some(true));
+        });
+    }
+
+    #[test]
+    fn test_try_new() {
+        run_test(|_| {
+            assert!(TimedDebouncer::<MockMonotonic, _>::try_new(false, 10.millis()).is_err());
+            MockMonotonic::add(1.millis());
+            assert!(TimedDebouncer::<MockMonotonic, bool, _>::try_new_unknown(10.millis()).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_observer() {
+        struct Counter(usize);
+        impl TransitionObserver<MockMonotonic, bool, InitializedValue<bool>> for Counter {
+            fn on_transition(
+                &mut self,
+                from: bool,
+                to: bool,
+                _at: fugit::TimerInstantU64<1_000_000>,
+            ) {
+                assert_ne!(from, to);
+                self.0 += 1;
+            }
+        }
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis())
+                .with_observer(Counter(0));
+            debouncer.update(true);
+            MockMonotonic::add(11.millis());
+            debouncer.update(true);
+            debouncer.update(true);
+            assert_eq!(debouncer.observer().0, 1);
+        });
+    }
+
+    #[test]
+    fn test_noop_observer_is_zero_sized() {
+        struct Ignore;
+        impl TransitionObserver<MockMonotonic, bool, InitializedValue<bool>> for Ignore {
+            fn on_transition(&mut self, _: bool, _: bool, _: fugit::TimerInstantU64<1_000_000>) {}
+        }
+        type Plain = TimedDebouncer<MockMonotonic, bool>;
+        type Observed = TimedDebouncer<MockMonotonic, bool, InitializedValue<bool>, Ignore>;
+        assert_eq!(core::mem::size_of::<NoopObserver>(), 0);
+        assert_eq!(
+            core::mem::size_of::<Plain>(),
+            core::mem::size_of::<Observed>()
+        );
+    }
+
+    #[test]
+    fn test_closure_observer() {
+        run_test(|_| {
+            let mut transitions = 0;
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis())
+                .with_observer(|from: bool, to: bool, _| {
+                    assert_ne!(from, to);
+                    transitions += 1;
+                });
+            debouncer.update(true);
+            MockMonotonic::add(11.millis());
+            debouncer.update(true);
+            assert_eq!(transitions, 1);
+        });
+    }
+
+    #[test]
+    fn test_rebase_clock() {
+        run_test(|_| {
+            let mut preserve = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            let mut restart = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            preserve.update(true);
+            restart.update(true);
+            MockMonotonic::add(6.millis());
+            let previous_now = MockMonotonic::now();
+            MockMonotonic::add(1_000.millis());
+            preserve.rebase_clock(
+                MockMonotonic::now(),
+                ClockRebase::PreserveElapsed { previous_now },
+            );
+            restart.rebase_clock(MockMonotonic::now(), ClockRebase::Restart);
+            assert!(!preserve.update(true).transitioned());
+            assert!(!restart.update(true).transitioned());
+            MockMonotonic::add(5.millis());
+            assert!(preserve.update(true).transitioned());
+            assert!(!restart.update(true).transitioned());
+            MockMonotonic::add(5.millis());
+            assert!(restart.update(true).transitioned());
+        });
+    }
+
+    #[test]
+    fn test_max_unstable_time() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            debouncer.set_max_unstable_time(Some(50.millis()));
+            let mut value = false;
+            let mut transitioned_at = None;
+            for t in 0..100 {
+                value = !value;
+                if debouncer.update(value).transitioned() {
+                    transitioned_at = Some(t);
+                    break;
+                }
+                MockMonotonic::add(1.millis());
+            }
+            assert_eq!(transitioned_at, Some(50));
+            assert!(debouncer.read_stable());
+        });
+    }
+
+    #[test]
+    fn test_flush() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            assert_eq!(debouncer.flush(), State::Stable { value: false });
+            debouncer.update(true);
+            assert_eq!(debouncer.pending_value(), Some(true));
+            assert_eq!(
+                debouncer.flush(),
+                State::Transitioned {
+                    stable: true,
+                    previous_stable: false
+                }
+            );
+            assert_eq!(debouncer.read(), State::Stable { value: true });
+        });
+    }
+
+    #[test]
+    fn test_parts_round_trip() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            debouncer.update(true);
+            MockMonotonic::add(5.millis());
+            let (last_stable, last_value, last_change_time, debounce_time) = debouncer.into_parts();
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::from_parts(
+                last_stable,
+                last_value,
+                last_change_time,
+                debounce_time,
+            );
+            assert_eq!(debouncer.pending_value(), Some(true));
+            debouncer.update(true);
+            assert_eq!(debouncer.candidate_match_count(), 2);
+            MockMonotonic::add(6.millis());
+            assert!(debouncer.read().transitioned());
+        });
+    }
+
+    #[test]
+    fn test_stable_for() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            MockMonotonic::add(100.millis());
+            debouncer.update(true);
+            assert!(!debouncer.stable_for(1.millis()));
+            MockMonotonic::add(5.millis());
+            debouncer.update(false);
+            MockMonotonic::add(20.millis());
+            assert!(debouncer.stable_for(20.millis()));
+            assert!(!debouncer.stable_for(21.millis()));
+            assert_eq!(debouncer.stable_duration().ticks(), 20_000);
+        });
+    }
+
+    #[test]
+    fn test_update_edge() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            assert_eq!(debouncer.update_edge(true), None);
+            MockMonotonic::add(11.millis());
+            assert_eq!(debouncer.update_edge(true), Some(Edge::Rising));
+            debouncer.update_edge(false);
+            MockMonotonic::add(11.millis());
+            assert_eq!(debouncer.update_edge(false), Some(Edge::Falling));
         });
     }
 
@@ -226,15 +1399,124 @@ mod tests {
             );
             MockMonotonic::add(11.millis()); // Simulate time passing
             let state = debouncer.update(false);
+            assert_eq!(state, State::Initialized { value: false });
+            assert!(state.transitioned());
+            let state = debouncer.update(false);
+            assert_eq!(state, State::Stable { value: false });
+        });
+    }
+
+    #[test]
+    fn test_zero_debounce_time() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 0.millis());
             assert_eq!(
-                state,
+                debouncer.update(true),
                 State::Transitioned {
-                    stable: false,
-                    previous_stable: None
+                    stable: true,
+                    previous_stable: false
                 }
             );
-            let state = debouncer.update(false);
-            assert_eq!(state, State::Stable { value: false });
+            assert!(crate::NonZeroDuration::<MockMonotonic>::new(0.millis()).is_none());
+            assert!(crate::NonZeroDuration::<MockMonotonic>::new(1.millis()).is_some());
+        });
+    }
+
+    #[test]
+    fn test_raw_value_accessors() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8, _>::new_unknown(10.millis());
+            debouncer.update(1);
+            assert_eq!(debouncer.stable_value_raw().try_get(), None);
+            assert_eq!(debouncer.last_value_raw(), UninitializedValue::from(1));
+            MockMonotonic::add(11.millis());
+            debouncer.update(1);
+            assert_eq!(debouncer.stable_value_raw(), UninitializedValue::from(1));
+            debouncer.update(2);
+            assert_eq!(debouncer.stable_value_raw().try_get(), Some(1));
+            assert_eq!(debouncer.last_value_raw().try_get(), Some(2));
+        });
+    }
+
+    #[test]
+    fn test_pending_value() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8>::new(0, 10.millis());
+            assert_eq!(debouncer.pending_value(), None);
+            debouncer.update(1);
+            assert_eq!(debouncer.pending_value(), Some(1));
+            debouncer.update(2);
+            assert_eq!(debouncer.pending_value(), Some(2));
+            debouncer.update(0);
+            assert_eq!(debouncer.pending_value(), None);
+            debouncer.update(2);
+            MockMonotonic::add(11.millis());
+            assert!(debouncer.update(2).transitioned());
+            assert_eq!(debouncer.pending_value(), None);
+        });
+    }
+
+    #[test]
+    fn test_previous_stable() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8>::new(0, 10.millis());
+            assert_eq!(debouncer.previous_stable(), 0);
+            for value in [1, 2] {
+                debouncer.update(value);
+                MockMonotonic::add(11.millis());
+                debouncer.update(value);
+            }
+            assert_eq!(debouncer.read_stable(), 2);
+            assert_eq!(debouncer.previous_stable(), 1);
+            debouncer.update(3);
+            assert_eq!(debouncer.previous_stable(), 1);
+        });
+    }
+
+    #[test]
+    fn test_is_settling() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            assert!(!debouncer.is_settling());
+            debouncer.update(true);
+            assert!(debouncer.is_settling());
+            MockMonotonic::add(11.millis());
+            // the debounce time elapsed, but the transition is only taken on the next update
+            assert!(!debouncer.is_settling());
+            assert!(debouncer.update(true).transitioned());
+            assert!(!debouncer.is_settling());
+        });
+    }
+
+    #[test]
+    fn test_candidate_match_count() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8>::new(0, 10.millis());
+            assert_eq!(debouncer.candidate_match_count(), 0);
+            debouncer.update(1);
+            debouncer.update(1);
+            debouncer.update(1);
+            assert_eq!(debouncer.candidate_match_count(), 3);
+            debouncer.update(2);
+            assert_eq!(debouncer.candidate_match_count(), 1);
+            debouncer.update(0);
+            assert_eq!(debouncer.candidate_match_count(), 0);
+            debouncer.update(2);
+            MockMonotonic::add(11.millis());
+            assert!(debouncer.update(2).transitioned());
+            assert_eq!(debouncer.candidate_match_count(), 0);
+        });
+    }
+
+    #[test]
+    fn test_stable_ref() {
+        run_test(|_| {
+            let mut debouncer = TimedDebouncer::<MockMonotonic, u8, _>::new_unknown(10.millis());
+            assert_eq!(debouncer.stable_ref(), &None);
+            debouncer.update(1);
+            MockMonotonic::add(11.millis());
+            debouncer.update(1);
+            assert_eq!(debouncer.stable_ref(), &Some(1));
         });
     }
 }
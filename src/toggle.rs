@@ -0,0 +1,83 @@
+use crate::{InitializedValue, Monotonic, TimedDebouncer, UninitializedValue, Value};
+
+/// Debounced toggle switch, e.g. an SPDT or rocker switch, with the positions `P`.
+///
+/// This is a thin wrapper over [`TimedDebouncer`] that reports the new position whenever the
+/// switch was toggled. If the position is unknown at boot, use [`Toggle::new_unknown`]; the first
+/// stable position is then reported as a toggle as well.
+pub struct Toggle<M: Monotonic, P, V: Value<T = P> = InitializedValue<P>> {
+    debouncer: TimedDebouncer<M, P, V>,
+}
+
+impl<M, P> Toggle<M, P, InitializedValue<P>>
+where
+    M: Monotonic,
+    P: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new [`Toggle`] with a known initial position.
+    pub fn new(initial_position: P, debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_position, debounce_time),
+        }
+    }
+}
+impl<M, P> Toggle<M, P, UninitializedValue<P>>
+where
+    M: Monotonic,
+    P: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new [`Toggle`] whose position is unknown until it became stable.
+    pub fn new_unknown(debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+        }
+    }
+}
+impl<M, P, V> Toggle<M, P, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    P: PartialEq + Copy,
+    V: Value<T = P> + Copy + From<P>,
+    V::V: Copy,
+{
+    /// Updates the toggle with the raw position and returns the new position if it was toggled.
+    pub fn update(&mut self, raw: P) -> Option<P> {
+        let state = self.debouncer.update(raw);
+        state.transitioned().then_some(raw)
+    }
+    /// Returns the current debounced position.
+    pub fn position(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Position {
+        Left,
+        Right,
+    }
+
+    #[test]
+    fn test_toggle_from_unknown() {
+        run_test(|_| {
+            let mut toggle = Toggle::<MockMonotonic, Position, _>::new_unknown(10.millis());
+            assert_eq!(toggle.update(Position::Left), None);
+            assert_eq!(toggle.position(), None);
+            MockMonotonic::add(11.millis());
+            assert_eq!(toggle.update(Position::Left), Some(Position::Left));
+            assert_eq!(toggle.update(Position::Right), None);
+            MockMonotonic::add(11.millis());
+            assert_eq!(toggle.update(Position::Right), Some(Position::Right));
+            assert_eq!(toggle.position(), Some(Position::Right));
+        });
+    }
+}
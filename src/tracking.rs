@@ -0,0 +1,74 @@
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer};
+
+/// Debounces whether a measured value equals a reference value that may change over time.
+///
+/// This is useful for "at target" indicators in closed-loop systems. The debounce window is
+/// restarted whenever either the measured or the reference value changes, so a change of the
+/// reference that the measured value follows immediately is not mistaken for a steady match.
+pub struct TrackingDebouncer<M: Monotonic, T> {
+    debouncer: TimedDebouncer<M, bool, InitializedValue<bool>>,
+    measured: T,
+    reference: T,
+}
+
+impl<M, T> TrackingDebouncer<M, T>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+{
+    /// Creates a new [`TrackingDebouncer`] that starts out stable with the equality of the given values.
+    pub fn new(measured: T, reference: T, debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(measured == reference, debounce_time),
+            measured,
+            reference,
+        }
+    }
+    /// Updates the debouncer with new measured and reference values and returns the current state
+    /// of their equality.
+    pub fn update(&mut self, measured: T, reference: T) -> State<bool, InitializedValue<bool>> {
+        if measured != self.measured || reference != self.reference {
+            self.debouncer.restart_window();
+            self.measured = measured;
+            self.reference = reference;
+        }
+        self.debouncer.update(measured == reference)
+    }
+    /// Read the last stable state of the equality.
+    pub fn read_stable(&self) -> bool {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_reference_change_restarts_window() {
+        run_test(|_| {
+            let mut debouncer = TrackingDebouncer::<MockMonotonic, u8>::new(0, 5, 10.millis());
+            debouncer.update(5, 5);
+            MockMonotonic::add(6.millis());
+            // reference moves and measured follows immediately
+            debouncer.update(6, 6);
+            MockMonotonic::add(6.millis());
+            assert!(!debouncer.update(6, 6).transitioned());
+            MockMonotonic::add(5.millis());
+            assert_eq!(
+                debouncer.update(6, 6),
+                State::Transitioned {
+                    stable: true,
+                    previous_stable: false
+                }
+            );
+            // a short mismatch does not leave the target
+            debouncer.update(6, 7);
+            MockMonotonic::add(2.millis());
+            assert_eq!(debouncer.update(7, 7), State::Stable { value: true });
+        });
+    }
+}
@@ -0,0 +1,91 @@
+use core::ops::Sub;
+
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Debouncer that measures how long it has been running, e.g. to correlate transition counts with
+/// the runtime for wear metrics.
+///
+/// Unlike [`TimedDebouncer::stable_duration`] the age is independent of the current value.
+pub struct UptimeDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    started_at: M::Instant,
+}
+
+impl<M, T> UptimeDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value.
+    pub fn new(initial_value: T, debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            started_at: M::now(),
+        }
+    }
+}
+impl<M, T> UptimeDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state.
+    pub fn new_unknown(debounce_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            started_at: M::now(),
+        }
+    }
+}
+impl<M, T, V> UptimeDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        self.debouncer.update(new_value)
+    }
+    /// Returns how long the debouncer has been running since it was created or
+    /// [`UptimeDebouncer::reset_age`] was called.
+    pub fn age(&self) -> M::Duration
+    where
+        M::Instant: Sub<Output = M::Duration>,
+    {
+        M::now() - self.started_at
+    }
+    /// Restarts the measurement of [`UptimeDebouncer::age`] at the current time.
+    pub fn reset_age(&mut self) {
+        self.started_at = M::now();
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_age() {
+        run_test(|_| {
+            MockMonotonic::add(100.millis());
+            let mut debouncer = UptimeDebouncer::<MockMonotonic, _>::new(false, 10.millis());
+            MockMonotonic::add(25.millis());
+            debouncer.update(true);
+            assert_eq!(debouncer.age().ticks(), 25_000);
+            debouncer.reset_age();
+            MockMonotonic::add(5.millis());
+            assert_eq!(debouncer.age().ticks(), 5_000);
+        });
+    }
+}
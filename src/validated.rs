@@ -0,0 +1,110 @@
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Debouncer that only accepts values from a valid set.
+///
+/// Samples for which the predicate returns `false` restart the debounce window but never become a
+/// candidate for the stable value. This is useful for multi-position switches, where the wiper
+/// briefly floats between detents while it is being turned.
+pub struct ValidatedDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    valid_set: fn(&T) -> bool,
+}
+
+impl<M, T> ValidatedDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value and a predicate for valid values.
+    pub fn new_with_valid_set(
+        initial_value: T,
+        debounce_time: M::Duration,
+        valid_set: fn(&T) -> bool,
+    ) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            valid_set,
+        }
+    }
+}
+impl<M, T> ValidatedDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state and a predicate for valid values.
+    pub fn new_unknown_with_valid_set(
+        debounce_time: M::Duration,
+        valid_set: fn(&T) -> bool,
+    ) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            valid_set,
+        }
+    }
+}
+impl<M, T, V> ValidatedDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Updates the debouncer state with a new value and returns the current state.
+    ///
+    /// An invalid value restarts the debounce window and is otherwise ignored.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        if (self.valid_set)(&new_value) {
+            self.debouncer.update(new_value)
+        } else {
+            self.debouncer.restart_window();
+            self.debouncer.read()
+        }
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+    /// Returns the underlying [`TimedDebouncer`].
+    pub fn debouncer(&self) -> &TimedDebouncer<M, T, V> {
+        &self.debouncer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    /// Valid detents of a switch, `0` is the floating wiper.
+    fn is_detent(value: &u8) -> bool {
+        *value != 0
+    }
+
+    #[test]
+    fn test_invalid_value_mid_window() {
+        run_test(|_| {
+            let mut switch = ValidatedDebouncer::<MockMonotonic, u8>::new_with_valid_set(
+                1,
+                10.millis(),
+                is_detent,
+            );
+            switch.update(2);
+            MockMonotonic::add(6.millis());
+            assert!(!switch.update(0).transitioned());
+            MockMonotonic::add(6.millis());
+            // the invalid value restarted the window
+            assert!(!switch.update(2).transitioned());
+            MockMonotonic::add(11.millis());
+            assert!(switch.update(2).transitioned_to(&2));
+            // an invalid value held for long is never adopted
+            switch.update(0);
+            MockMonotonic::add(20.millis());
+            assert_eq!(switch.update(0), State::Stable { value: 2 });
+        });
+    }
+}
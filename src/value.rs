@@ -6,18 +6,31 @@ mod private {
 
 /// Implementation detail to abstract away the differences between initialized and uninitialized debouncers
 pub trait Value: Deref<Target = Self::V> + private::Sealed {
+    /// The debounced value type.
     type T;
+    /// The type used to report a value, which is `Option<T>` if the value may be unknown.
     type V;
+    /// Whether a debouncer using this type starts with a known value.
+    const IS_INITIALIZED: bool;
+    /// Returns the stored value.
     fn get(&self) -> Self::V;
+    /// Returns the stored value, or `None` if it is unknown.
     fn try_get(&self) -> Option<Self::T>;
+    /// Returns the value reported while the value is unknown.
     fn default() -> Self::V;
+    /// Converts a known value to the reported type.
+    fn wrap(value: Self::T) -> Self::V;
+    /// Borrows the known value of a reported value, or `None` if it is unknown.
+    fn value_ref(value: &Self::V) -> Option<&Self::T>;
 }
+/// Stored value of a debouncer that may start with an unknown state.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub(crate) struct UninitializedValue<T>(Option<T>);
+pub struct UninitializedValue<T>(Option<T>);
 impl<T> private::Sealed for UninitializedValue<T> {}
 impl<T: Copy> Value for UninitializedValue<T> {
     type T = T;
     type V = Option<T>;
+    const IS_INITIALIZED: bool = false;
     #[inline(always)]
     fn get(&self) -> Self::V {
         self.0
@@ -29,6 +42,14 @@ impl<T: Copy> Value for UninitializedValue<T> {
     fn default() -> Self::V {
         None
     }
+    #[inline(always)]
+    fn wrap(value: Self::T) -> Self::V {
+        Some(value)
+    }
+    #[inline(always)]
+    fn value_ref(value: &Self::V) -> Option<&Self::T> {
+        value.as_ref()
+    }
 }
 impl<T> Default for UninitializedValue<T> {
     fn default() -> Self {
@@ -48,12 +69,14 @@ impl<T> From<T> for UninitializedValue<T> {
         UninitializedValue(Some(value))
     }
 }
+/// Stored value of a debouncer that always has a known state.
 #[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
 pub struct InitializedValue<T>(T);
 impl<T> private::Sealed for InitializedValue<T> {}
 impl<T: Copy> Value for InitializedValue<T> {
     type T = T;
     type V = T;
+    const IS_INITIALIZED: bool = true;
     #[inline(always)]
     fn get(&self) -> Self::V {
         self.0
@@ -67,6 +90,14 @@ impl<T: Copy> Value for InitializedValue<T> {
         // Cannot be reached, beacause `try_get()` always returns `Some`
         unreachable!()
     }
+    #[inline(always)]
+    fn wrap(value: Self::T) -> Self::V {
+        value
+    }
+    #[inline(always)]
+    fn value_ref(value: &Self::V) -> Option<&Self::T> {
+        Some(value)
+    }
 }
 impl<T> InitializedValue<T> {
     pub(crate) const fn new(value: T) -> Self {
@@ -0,0 +1,124 @@
+use crate::{InitializedValue, Monotonic, State, TimedDebouncer, UninitializedValue, Value};
+
+/// Debouncer that passes the input through undebounced during a warmup time after it was created.
+///
+/// During the warmup every change of the input is accepted immediately, so the debouncer quickly
+/// reaches a plausible state right after power-up. From the end of the warmup on, i.e. for updates
+/// at or after the creation time plus the warmup time, a new value has to stay for the debounce
+/// time as usual. A value that is pending at the end of the warmup keeps the time it was first
+/// seen, it is not restarted.
+pub struct WarmupDebouncer<M: Monotonic, T, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
+    warmup_time: M::Duration,
+    started_at: M::Instant,
+}
+
+impl<M, T> WarmupDebouncer<M, T, InitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer with a known initial value. The warmup starts now.
+    pub fn new(initial_value: T, debounce_time: M::Duration, warmup_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            warmup_time,
+            started_at: M::now(),
+        }
+    }
+}
+impl<M, T> WarmupDebouncer<M, T, UninitializedValue<T>>
+where
+    M: Monotonic,
+    T: Copy,
+    M::Duration: Copy,
+{
+    /// Creates a new Debouncer that starts with an unkown state. The warmup starts now.
+    pub fn new_unknown(debounce_time: M::Duration, warmup_time: M::Duration) -> Self {
+        Self {
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
+            warmup_time,
+            started_at: M::now(),
+        }
+    }
+}
+impl<M, T, V> WarmupDebouncer<M, T, V>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+    T: PartialEq + Copy,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
+    /// Get the warmup time.
+    pub fn warmup_time(&self) -> M::Duration {
+        self.warmup_time
+    }
+    /// Restarts the warmup at the current time.
+    pub fn restart_warmup(&mut self) {
+        self.started_at = M::now();
+    }
+    /// Checks if the warmup is still running at `now`.
+    fn is_warming_up(&self, now: M::Instant) -> bool {
+        now < self.started_at + self.warmup_time
+    }
+    /// Updates the debouncer state with a new value and returns the current state.
+    pub fn update(&mut self, new_value: T) -> State<T, V> {
+        let now = M::now();
+        let state = self.debouncer.update_at(new_value, now);
+        if self.is_warming_up(now) {
+            if let Some(state) = self.debouncer.transition_pending(now) {
+                return state;
+            }
+        }
+        state
+    }
+    /// Checks if calling [`WarmupDebouncer::update`] with `new_value` right now would transition,
+    /// without changing the debouncer.
+    pub fn would_transition(&self, new_value: T) -> bool {
+        let changes = self.debouncer.settled_value() != Some(new_value);
+        (changes && self.is_warming_up(M::now())) || self.debouncer.would_transition(new_value)
+    }
+    /// Read the last stable value.
+    pub fn read_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_warmup_time() {
+        run_test(|_| {
+            let mut debouncer =
+                WarmupDebouncer::<MockMonotonic, _>::new(false, 10.millis(), 50.millis());
+            assert!(debouncer.update(true).transitioned());
+            MockMonotonic::add(49.millis());
+            assert!(debouncer.update(false).transitioned());
+            MockMonotonic::add(1.millis());
+            assert!(!debouncer.update(true).transitioned());
+            MockMonotonic::add(10.millis());
+            assert!(debouncer.update(true).transitioned());
+        });
+    }
+
+    #[test]
+    fn test_would_transition_during_warmup() {
+        run_test(|_| {
+            let mut debouncer =
+                WarmupDebouncer::<MockMonotonic, u8>::new(0, 10.millis(), 50.millis());
+            assert!(debouncer.would_transition(1));
+            assert!(!debouncer.would_transition(0));
+            assert_eq!(debouncer.read_stable(), 0);
+            assert!(debouncer.update(1).transitioned());
+            MockMonotonic::add(50.millis());
+            assert!(!debouncer.would_transition(2));
+            assert!(!debouncer.update(2).transitioned());
+        });
+    }
+}
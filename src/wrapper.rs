@@ -1,6 +1,12 @@
-use core::{cell::RefCell, convert::Infallible};
+use core::{
+    convert::Infallible,
+    ops::Sub,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
-use crate::{InitializedValue, Monotonic, State, TimedDebouncer};
+use crate::{
+    InitializedValue, Monotonic, PhysicalDuration, State, TimedDebouncer, UninitializedValue, Value,
+};
 
 /// Trait to interface with [`DebouncedInput`].
 pub trait Input<T> {
@@ -8,12 +14,64 @@ pub trait Input<T> {
     fn read(&mut self) -> T;
 }
 
+impl Input<bool> for &AtomicBool {
+    fn read(&mut self) -> bool {
+        self.load(Ordering::Relaxed)
+    }
+}
+
+/// Input adapter that turns an infallible input of `Result<T, Infallible>` into an input of `T`.
+///
+/// This is statically safe, since the error type is uninhabited. `embedded-hal` pins implement
+/// `Input<Result<PinState, Error>>`, so wrapping a pin with an `Infallible` error in this adapter
+/// debounces a plain `PinState`. The pin itself does not implement `Input<PinState>`, so
+/// `pin.debounce(debounce_time)` always infers the `Result` value type.
+pub struct UnwrapInfallible<I>(pub I);
+
+impl<T, I: Input<Result<T, Infallible>>> Input<T> for UnwrapInfallible<I> {
+    fn read(&mut self) -> T {
+        unwrap_infallible(self.0.read())
+    }
+}
+
+fn unwrap_infallible<T>(result: Result<T, Infallible>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(never) => match never {},
+    }
+}
+
+/// Input adapter that inverts the level of an active-low input, see
+/// [`DebouncedInput::new_active_low`].
+pub struct Inverted<I>(pub I);
+
+impl<T: ActiveLow, I: Input<T>> Input<T> for Inverted<I> {
+    fn read(&mut self) -> T {
+        self.0.read().invert()
+    }
+}
+
 /// Generic debouncing wrapper for any input implementing [`Input`].
-pub struct DebouncedInput<M: Monotonic, T: Copy, I> {
-    debouncer: TimedDebouncer<M, T, InitializedValue<T>>,
+pub struct DebouncedInput<M: Monotonic, T: Copy, I, V: Value<T = T> = InitializedValue<T>> {
+    debouncer: TimedDebouncer<M, T, V>,
     input: I,
 }
 
+/// Value types of inputs that can be active-low, see [`DebouncedInput::new_active_low`].
+///
+/// This is implemented for `bool` and, if the corresponding features are enabled, for the
+/// `PinState` results of `embedded-hal` pins.
+pub trait ActiveLow {
+    /// Inverts the level of the value.
+    fn invert(self) -> Self;
+}
+
+impl ActiveLow for bool {
+    fn invert(self) -> Self {
+        !self
+    }
+}
+
 impl<M, T, I> DebouncedInput<M, T, I>
 where
     I: Input<T>,
@@ -23,27 +81,116 @@ where
 {
     /// Creates a new [`DebouncedInput`] by wrapping an [`Input`]
     pub fn new(mut input: I, debounce_time: M::Duration) -> Self {
+        let initial_value = input.read();
+        Self {
+            debouncer: TimedDebouncer::new(initial_value, debounce_time),
+            input,
+        }
+    }
+}
+
+impl<M, T, I> DebouncedInput<M, T, Inverted<I>>
+where
+    I: Input<T>,
+    M: Monotonic,
+    M::Duration: Copy,
+    T: Copy + PartialEq + ActiveLow,
+{
+    /// Creates a new [`DebouncedInput`] for an active-low input, by wrapping it in [`Inverted`].
+    ///
+    /// The level read from the input is inverted before debouncing, so a pressed active-low
+    /// button reads as `true` or `PinState::High`. The `embedded-hal` implementations report this
+    /// logical level as well, i.e. `is_high` returns `true` while the input is active. For
+    /// `embedded-hal` 1.0 the pin has to be wrapped in [`UnwrapInfallible`] first, since only a
+    /// debounced `PinState` implements `InputPin`.
+    /// This is only available for the value types implementing [`ActiveLow`].
+    pub fn new_active_low(input: I, debounce_time: M::Duration) -> Self {
+        Self::new(Inverted(input), debounce_time)
+    }
+}
+
+impl<M, T, I> DebouncedInput<M, T, I, UninitializedValue<T>>
+where
+    I: Input<T>,
+    M: Monotonic,
+    M::Duration: Copy,
+    T: Copy + PartialEq,
+{
+    /// Creates a new [`DebouncedInput`] that does not trust the first read.
+    ///
+    /// Unlike [`new`](DebouncedInput::new), the input is not read on construction. The stable
+    /// value stays unknown until the input has settled for `debounce_time`, so a glitch during
+    /// power-up is not latched as the initial stable value.
+    pub fn new_settle(input: I, debounce_time: M::Duration) -> Self {
         Self {
-            debouncer: TimedDebouncer::new(input.read(), debounce_time),
+            debouncer: TimedDebouncer::new_unknown(debounce_time),
             input,
         }
     }
+}
+
+impl<M, T, I, V> DebouncedInput<M, T, I, V>
+where
+    I: Input<T>,
+    M: Monotonic,
+    M::Duration: Copy,
+    T: Copy + PartialEq,
+    V: Value<T = T> + Copy + From<T>,
+    V::V: Copy,
+{
     /// Read the current state of the input.
-    pub fn read(&mut self) -> State<T, InitializedValue<T>> {
+    pub fn read(&mut self) -> State<T, V> {
         self.debouncer.update(self.input.read())
     }
 }
 
-impl<M, T, I> DebouncedInput<M, T, I>
+impl<M, T, I, V> DebouncedInput<M, T, I, V>
 where
     M: Monotonic,
     M::Duration: Copy,
     T: Copy + PartialEq,
+    V: Value<T = T>,
+    V::V: Copy,
 {
+    /// Returns the last debounced value without reading the input or updating the debouncer.
+    ///
+    /// This is the same as [`DebouncedInput::read_stable`], named to contrast it with
+    /// [`DebouncedInput::read`] for inputs where reading has side effects or costs.
+    pub fn peek_stable(&self) -> V::V {
+        self.debouncer.read_stable()
+    }
+    /// Replaces the wrapped input with `new_input` and returns the old one.
+    ///
+    /// The debouncer state is not reset by the swap: the stable value, a pending value and its
+    /// timing are kept, so switching between sources that agree causes no transition.
+    pub fn replace_input(&mut self, new_input: I) -> I {
+        core::mem::replace(&mut self.input, new_input)
+    }
     /// Read the last stable state of the input.
-    pub fn read_stable(&self) -> T {
+    pub fn read_stable(&self) -> V::V {
         self.debouncer.read_stable()
     }
+    /// Checks if the input is currently settling. See [`TimedDebouncer::is_settling`].
+    pub fn is_settling(&self) -> bool {
+        self.debouncer.is_settling()
+    }
+    /// Returns the time left until the pending value becomes stable, or zero if no value is pending.
+    /// See [`TimedDebouncer::remaining_debounce_time`].
+    pub fn remaining_debounce_time(&self) -> M::Duration
+    where
+        M::Instant: Sub<Output = M::Duration>,
+    {
+        self.debouncer.remaining_debounce_time()
+    }
+    /// Returns how far the pending value has progressed towards becoming stable, from `0.0` to `1.0`.
+    /// See [`TimedDebouncer::settle_progress`].
+    pub fn settle_progress(&self) -> f32
+    where
+        M::Instant: Sub<Output = M::Duration>,
+        M::Duration: PhysicalDuration,
+    {
+        self.debouncer.settle_progress()
+    }
 }
 
 /// Trait to simplify conversion to DebouncedInput.
@@ -77,16 +224,16 @@ where
 #[cfg(feature = "ehal0")]
 impl<M, I> InputPinV0 for DebouncedInput<M, Result<PinStateV0, Infallible>, I>
 where
-    I: InputPinV0,
+    I: Input<Result<PinStateV0, Infallible>>,
     M: Monotonic,
     M::Duration: Copy,
 {
     type Error = Infallible;
     fn is_high(&self) -> Result<bool, Self::Error> {
-        self.read_stable().and_then(|s| Ok(s == PinStateV0::High))
+        self.read_stable().map(|s| s == PinStateV0::High)
     }
     fn is_low(&self) -> Result<bool, Self::Error> {
-        self.read_stable().and_then(|s| Ok(s == PinStateV0::Low))
+        self.read_stable().map(|s| s == PinStateV0::Low)
     }
 }
 
@@ -156,6 +303,38 @@ impl<I: InputPinV0> Input<Result<PinStateV0, I::Error>> for I {
     }
 }
 
+#[cfg_attr(docsrs, doc(cfg(feature = "ehal0")))]
+#[cfg(feature = "ehal0")]
+impl<M, I> InputPinV0 for DebouncedInput<M, PinStateV0, I>
+where
+    M: Monotonic,
+    M::Duration: Copy,
+{
+    type Error = Infallible;
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.read_stable() == PinStateV0::High)
+    }
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        Ok(self.read_stable() == PinStateV0::Low)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ehal0")))]
+#[cfg(feature = "ehal0")]
+impl ActiveLow for PinStateV0 {
+    fn invert(self) -> Self {
+        !self
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ehal0")))]
+#[cfg(feature = "ehal0")]
+impl<E> ActiveLow for Result<PinStateV0, E> {
+    fn invert(self) -> Self {
+        self.map(|state| !state)
+    }
+}
+
 #[cfg_attr(docsrs, doc(cfg(feature = "ehal1")))]
 #[cfg(feature = "ehal1")]
 impl<I: InputPinV1> Input<Result<PinStateV1, I::Error>> for I {
@@ -167,3 +346,237 @@ impl<I: InputPinV1> Input<Result<PinStateV1, I::Error>> for I {
         }
     }
 }
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ehal1")))]
+#[cfg(feature = "ehal1")]
+impl<M: Monotonic, I> ehal1::digital::ErrorType for DebouncedInput<M, PinStateV1, I> {
+    type Error = Infallible;
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ehal1")))]
+#[cfg(feature = "ehal1")]
+impl<M, I> InputPinV1 for DebouncedInput<M, PinStateV1, I>
+where
+    I: Input<PinStateV1>,
+    M: Monotonic,
+    M::Duration: Copy,
+{
+    /// Updates the debouncer and checks if the stable state is high.
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.read().stable() == PinStateV1::High)
+    }
+    /// Updates the debouncer and checks if the stable state is low.
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.read().stable() == PinStateV1::Low)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ehal1")))]
+#[cfg(feature = "ehal1")]
+impl ActiveLow for PinStateV1 {
+    fn invert(self) -> Self {
+        !self
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ehal1")))]
+#[cfg(feature = "ehal1")]
+impl<E> ActiveLow for Result<PinStateV1, E> {
+    fn invert(self) -> Self {
+        self.map(|state| !state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{run_test, MockMonotonic};
+    use fugit::ExtU64;
+
+    #[test]
+    fn test_atomic_bool() {
+        static FLAG: AtomicBool = AtomicBool::new(false);
+        run_test(|_| {
+            let mut input: DebouncedInput<MockMonotonic, _, _> = (&FLAG).debounce(5.millis());
+            FLAG.store(true, Ordering::Relaxed);
+            MockMonotonic::add(1.millis());
+            input.read();
+            MockMonotonic::add(5.millis());
+            assert!(input.read().transitioned());
+        });
+    }
+
+    #[test]
+    fn test_peek_stable() {
+        struct Counting(u32);
+        impl Input<bool> for Counting {
+            fn read(&mut self) -> bool {
+                self.0 += 1;
+                true
+            }
+        }
+        run_test(|_| {
+            let mut input: DebouncedInput<MockMonotonic, _, _> = Counting(0).debounce(5.millis());
+            input.read();
+            assert!(input.peek_stable());
+            assert!(input.peek_stable());
+            let reads = input.replace_input(Counting(0)).0;
+            assert_eq!(reads, 2);
+        });
+    }
+
+    #[test]
+    fn test_replace_input() {
+        static PRIMARY: AtomicBool = AtomicBool::new(true);
+        static BACKUP: AtomicBool = AtomicBool::new(true);
+        run_test(|_| {
+            let mut input: DebouncedInput<MockMonotonic, _, _> = (&PRIMARY).debounce(5.millis());
+            let old = input.replace_input(&BACKUP);
+            assert!(core::ptr::eq(old, &PRIMARY));
+            assert_eq!(input.read(), State::Stable { value: true });
+            BACKUP.store(false, Ordering::Relaxed);
+            input.read();
+            MockMonotonic::add(5.millis());
+            assert!(input.read().transitioned_to(&false));
+        });
+    }
+
+    #[test]
+    fn test_new_settle() {
+        static FLAG: AtomicBool = AtomicBool::new(true);
+        run_test(|_| {
+            let mut input =
+                DebouncedInput::<MockMonotonic, bool, _, _>::new_settle(&FLAG, 5.millis());
+            assert_eq!(input.read_stable(), None);
+            MockMonotonic::add(1.millis());
+            FLAG.store(false, Ordering::Relaxed);
+            assert!(matches!(input.read(), State::Unstable { .. }));
+            MockMonotonic::add(5.millis());
+            assert_eq!(input.read(), State::Initialized { value: false });
+            assert_eq!(input.read_stable(), Some(false));
+        });
+    }
+
+    #[test]
+    fn test_unwrap_infallible() {
+        struct Pin(bool);
+        impl Input<Result<bool, Infallible>> for Pin {
+            fn read(&mut self) -> Result<bool, Infallible> {
+                Ok(self.0)
+            }
+        }
+        run_test(|_| {
+            let input: DebouncedInput<MockMonotonic, bool, _> =
+                UnwrapInfallible(Pin(true)).debounce(5.millis());
+            assert!(input.read_stable());
+        });
+    }
+
+    #[cfg(feature = "ehal1")]
+    #[test]
+    fn test_infallible_pin_state() {
+        struct Pin;
+        impl ehal1::digital::ErrorType for Pin {
+            type Error = Infallible;
+        }
+        impl InputPinV1 for Pin {
+            fn is_high(&mut self) -> Result<bool, Infallible> {
+                Ok(true)
+            }
+            fn is_low(&mut self) -> Result<bool, Infallible> {
+                Ok(false)
+            }
+        }
+        run_test(|_| {
+            // without annotating the value type the pin is debounced as a `Result`
+            let input: DebouncedInput<MockMonotonic, _, _> = Pin.debounce(5.millis());
+            assert_eq!(input.read_stable(), Ok(PinStateV1::High));
+            let mut input: DebouncedInput<MockMonotonic, _, _> =
+                UnwrapInfallible(Pin).debounce(5.millis());
+            assert_eq!(input.read_stable(), PinStateV1::High);
+            assert!(input.is_high().unwrap());
+        });
+    }
+
+    #[test]
+    fn test_active_low() {
+        static LEVEL: AtomicBool = AtomicBool::new(true);
+        run_test(|_| {
+            let mut button =
+                DebouncedInput::<MockMonotonic, _, _>::new_active_low(&LEVEL, 5.millis());
+            assert!(!button.read_stable());
+            LEVEL.store(false, Ordering::Relaxed);
+            button.read();
+            MockMonotonic::add(6.millis());
+            assert!(button.read().transitioned_to(&true));
+        });
+    }
+
+    #[test]
+    fn test_is_settling() {
+        static FLAG: AtomicBool = AtomicBool::new(false);
+        run_test(|_| {
+            let mut input: DebouncedInput<MockMonotonic, _, _> = (&FLAG).debounce(5.millis());
+            assert!(!input.is_settling());
+            FLAG.store(true, Ordering::Relaxed);
+            input.read();
+            assert!(input.is_settling());
+            MockMonotonic::add(5.millis());
+            assert!(input.read().transitioned());
+            assert!(!input.is_settling());
+        });
+    }
+
+    #[cfg(feature = "ehal1")]
+    #[test]
+    fn test_active_low_pin() {
+        static PRESSED: AtomicBool = AtomicBool::new(false);
+        struct Pin;
+        impl ehal1::digital::ErrorType for Pin {
+            type Error = Infallible;
+        }
+        impl InputPinV1 for Pin {
+            fn is_high(&mut self) -> Result<bool, Infallible> {
+                Ok(!PRESSED.load(Ordering::Relaxed))
+            }
+            fn is_low(&mut self) -> Result<bool, Infallible> {
+                Ok(PRESSED.load(Ordering::Relaxed))
+            }
+        }
+        run_test(|_| {
+            let mut button = DebouncedInput::<MockMonotonic, PinStateV1, _>::new_active_low(
+                UnwrapInfallible(Pin),
+                5.millis(),
+            );
+            assert!(button.is_low().unwrap());
+            PRESSED.store(true, Ordering::Relaxed);
+            assert!(!button.is_high().unwrap());
+            MockMonotonic::add(6.millis());
+            assert!(button.is_high().unwrap());
+        });
+    }
+
+    #[cfg(feature = "ehal0")]
+    #[test]
+    fn test_active_low_pin_v0() {
+        struct Pin;
+        impl InputPinV0 for Pin {
+            type Error = Infallible;
+            fn is_high(&self) -> Result<bool, Infallible> {
+                Ok(false)
+            }
+            fn is_low(&self) -> Result<bool, Infallible> {
+                Ok(true)
+            }
+        }
+        run_test(|_| {
+            let button = DebouncedInput::<MockMonotonic, _, _>::new_active_low(Pin, 5.millis());
+            assert_eq!(InputPinV0::is_high(&button), Ok(true));
+            let button = DebouncedInput::<MockMonotonic, PinStateV0, _>::new_active_low(
+                UnwrapInfallible(Pin),
+                5.millis(),
+            );
+            assert_eq!(InputPinV0::is_high(&button), Ok(true));
+        });
+    }
+}